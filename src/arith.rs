@@ -16,12 +16,13 @@
 use crate::{
     helpers::IntHelper,
     traits::ToFixed,
-    types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8},
+    types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8, Sum as FracSum},
     wide_div::WideDivRem,
     FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
     FixedU8,
 };
 use core::{
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
     iter::{Product, Sum},
     ops::{
         Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div,
@@ -29,6 +30,59 @@ use core::{
         SubAssign,
     },
 };
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/**
+An error which can be returned by [`div_exact`].
+
+# Examples
+
+```rust
+use substrate_fixed::{types::I16F16, DivError};
+let one = I16F16::from_num(1);
+let three = I16F16::from_num(3);
+assert_eq!(one.div_exact(I16F16::from_num(0)), Err(DivError::DivByZero));
+assert_eq!(one.div_exact(three), Err(DivError::Inexact(one / three)));
+println!("Division error: {}", DivError::<I16F16>::Overflow);
+```
+
+[`div_exact`]: struct.FixedI32.html#method.div_exact
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DivError<F> {
+    /// The quotient does not fit in the fixed-point type.
+    Overflow,
+    /// The divisor is zero.
+    DivByZero,
+    /// The quotient cannot be represented exactly. The error holds
+    /// the quotient truncated towards zero, which is what the `/`
+    /// operator returns.
+    Inexact(F),
+}
+
+impl<F> DivError<F> {
+    fn message(&self) -> &str {
+        match self {
+            DivError::Overflow => "overflow",
+            DivError::DivByZero => "division by zero",
+            DivError::Inexact(_) => "quotient cannot be represented exactly",
+        }
+    }
+}
+
+impl<F> Display for DivError<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(self.message(), f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<F: Debug> Error for DivError<F> {
+    fn description(&self) -> &str {
+        self.message()
+    }
+}
 
 macro_rules! refs {
     (impl $Imp:ident for $Fixed:ident$(($LeEqU:ident))* { $method:ident }) => {
@@ -296,6 +350,48 @@ macro_rules! fixed_arith {
         pass! { impl BitXor for $Fixed { bitxor } }
         pass_assign! { impl BitXorAssign for $Fixed { bitxor_assign } }
 
+        impl<Frac: $LeEqU> Add<$Inner> for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn add(self, rhs: $Inner) -> $Fixed<Frac> {
+                // add to the integer part only, so that the result is
+                // correct even if rhs itself does not fit
+                let int = self.int().to_num::<$Inner>() + rhs;
+                Self::from_bits(Self::from_num(int).to_bits() | self.frac().to_bits())
+            }
+        }
+
+        refs! { impl Add<$Inner> for $Fixed($LeEqU) { add } }
+
+        impl<Frac: $LeEqU> AddAssign<$Inner> for $Fixed<Frac> {
+            #[inline]
+            fn add_assign(&mut self, rhs: $Inner) {
+                *self = (*self).add(rhs);
+            }
+        }
+
+        refs_assign! { impl AddAssign<$Inner> for $Fixed($LeEqU) { add_assign } }
+
+        impl<Frac: $LeEqU> Sub<$Inner> for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn sub(self, rhs: $Inner) -> $Fixed<Frac> {
+                let int = self.int().to_num::<$Inner>() - rhs;
+                Self::from_bits(Self::from_num(int).to_bits() | self.frac().to_bits())
+            }
+        }
+
+        refs! { impl Sub<$Inner> for $Fixed($LeEqU) { sub } }
+
+        impl<Frac: $LeEqU> SubAssign<$Inner> for $Fixed<Frac> {
+            #[inline]
+            fn sub_assign(&mut self, rhs: $Inner) {
+                *self = (*self).sub(rhs);
+            }
+        }
+
+        refs_assign! { impl SubAssign<$Inner> for $Fixed($LeEqU) { sub_assign } }
+
         impl<Frac: $LeEqU> Mul<$Inner> for $Fixed<Frac> {
             type Output = $Fixed<Frac>;
             #[inline]
@@ -400,6 +496,8 @@ macro_rules! fixed_arith {
             }
         }
 
+        /// Sums the values starting from zero. Like repeated `+`, this
+        /// panics on overflow when debug assertions are enabled.
         impl<Frac> Sum<$Fixed<Frac>> for $Fixed<Frac> {
             fn sum<I>(iter: I) -> $Fixed<Frac>
             where
@@ -409,6 +507,8 @@ macro_rules! fixed_arith {
             }
         }
 
+        /// Sums the values starting from zero. Like repeated `+`, this
+        /// panics on overflow when debug assertions are enabled.
         impl<'a, Frac: 'a> Sum<&'a $Fixed<Frac>> for $Fixed<Frac> {
             fn sum<I>(iter: I) -> $Fixed<Frac>
             where
@@ -418,6 +518,14 @@ macro_rules! fixed_arith {
             }
         }
 
+        /// Multiplies the values. The product of no values is one. Like
+        /// repeated `*`, this panics on overflow when debug assertions are
+        /// enabled.
+        ///
+        /// If one cannot be represented, as for `U0F*`, `I0F*` and `I1F*`,
+        /// the product of no values panics when debug assertions are
+        /// enabled, and otherwise wraps to 0 for `U0F*` and `I0F*` or to
+        /// `-1` for `I1F*`.
         impl<Frac: $LeEqU> Product<$Fixed<Frac>> for $Fixed<Frac> {
             fn product<I>(mut iter: I) -> $Fixed<Frac>
             where
@@ -430,6 +538,14 @@ macro_rules! fixed_arith {
             }
         }
 
+        /// Multiplies the values. The product of no values is one. Like
+        /// repeated `*`, this panics on overflow when debug assertions are
+        /// enabled.
+        ///
+        /// If one cannot be represented, as for `U0F*`, `I0F*` and `I1F*`,
+        /// the product of no values panics when debug assertions are
+        /// enabled, and otherwise wraps to 0 for `U0F*` and `I0F*` or to
+        /// `-1` for `I1F*`.
         impl<'a, Frac: 'a + $LeEqU> Product<&'a $Fixed<Frac>> for $Fixed<Frac> {
             fn product<I>(mut iter: I) -> $Fixed<Frac>
             where
@@ -455,9 +571,77 @@ fixed_arith! { FixedI32(i32, LeEqU32, 32), Signed }
 fixed_arith! { FixedI64(i64, LeEqU64, 64), Signed }
 fixed_arith! { FixedI128(i128, LeEqU128, 128), Signed }
 
+macro_rules! fixed_wide_mul {
+    (
+        $Fixed:ident($Inner:ty, $LeEqU:ident),
+        $Double:ident($DoubleInner:ty, $DoubleLeEqU:ident),
+        $Signedness:tt
+    ) => {
+        impl<Frac: $LeEqU> $Fixed<Frac> {
+            comment! {
+                "Multiplies two fixed-point numbers and returns the full
+double-width product.
+
+The result is a [`", stringify!($Double), "`] with twice as many
+fractional bits, so no bits are lost and the multiplication cannot
+overflow.
+
+# Examples
+
+```rust
+use substrate_fixed::{
+    types::extra::{U4, U8},
+    ", stringify!($Fixed), ", ", stringify!($Double), ",
+};
+type Fix = ", stringify!($Fixed), "<U4>;
+type Wide = ", stringify!($Double), "<U8>;
+// 1.5 × 2.25 = 3.375 needs more than four fractional bits
+let prod = Fix::from_num(1.5).wide_mul(Fix::from_num(2.25));
+assert_eq!(prod, Wide::from_num(3.375));
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "let neg = Fix::from_num(-1.5);
+assert_eq!(neg.wide_mul(Fix::from_num(2.25)), -prod);
+",
+                },
+                "let max = Fix::max_value();
+let max_bits = ", stringify!($DoubleInner), "::from(max.to_bits());
+assert_eq!(max.wide_mul(max), Wide::from_bits(max_bits * max_bits));
+```
+
+[`", stringify!($Double), "`]: struct.", stringify!($Double), ".html
+";
+                #[inline]
+                pub fn wide_mul(self, rhs: $Fixed<Frac>) -> $Double<FracSum<Frac, Frac>>
+                where
+                    Frac: Add<Frac>,
+                    FracSum<Frac, Frac>: $DoubleLeEqU,
+                {
+                    let lhs = <$DoubleInner>::from(self.to_bits());
+                    let rhs = <$DoubleInner>::from(rhs.to_bits());
+                    $Double::from_bits(lhs * rhs)
+                }
+            }
+        }
+    };
+}
+
+fixed_wide_mul! { FixedU8(u8, LeEqU8), FixedU16(u16, LeEqU16), Unsigned }
+fixed_wide_mul! { FixedU16(u16, LeEqU16), FixedU32(u32, LeEqU32), Unsigned }
+fixed_wide_mul! { FixedU32(u32, LeEqU32), FixedU64(u64, LeEqU64), Unsigned }
+fixed_wide_mul! { FixedU64(u64, LeEqU64), FixedU128(u128, LeEqU128), Unsigned }
+fixed_wide_mul! { FixedI8(i8, LeEqU8), FixedI16(i16, LeEqU16), Signed }
+fixed_wide_mul! { FixedI16(i16, LeEqU16), FixedI32(i32, LeEqU32), Signed }
+fixed_wide_mul! { FixedI32(i32, LeEqU32), FixedI64(i64, LeEqU64), Signed }
+fixed_wide_mul! { FixedI64(i64, LeEqU64), FixedI128(i128, LeEqU128), Signed }
+
 pub(crate) trait MulDivOverflow: Sized {
     fn mul_overflow(self, rhs: Self, frac_nbits: u32) -> (Self, bool);
     fn div_overflow(self, rhs: Self, frac_nbits: u32) -> (Self, bool);
+    // returns the truncated quotient, overflow, and whether the division was exact
+    fn div_exact_overflow(self, rhs: Self, frac_nbits: u32) -> (Self, bool, bool);
+    fn mul_add_overflow(self, mul: Self, add: Self, frac_nbits: u32) -> (Self, bool);
 }
 
 macro_rules! mul_div_widen {
@@ -487,6 +671,33 @@ macro_rules! mul_div_widen {
                 };
                 (quot, overflow)
             }
+
+            #[inline]
+            fn div_exact_overflow(self, rhs: $Single, frac_nbits: u32) -> ($Single, bool, bool) {
+                let (quot, overflow) = self.div_overflow(rhs, frac_nbits);
+                let lhs2 = <$Double>::from(self) << frac_nbits;
+                (quot, overflow, lhs2 % <$Double>::from(rhs) == 0)
+            }
+
+            #[inline]
+            fn mul_add_overflow(
+                self,
+                mul: $Single,
+                add: $Single,
+                frac_nbits: u32,
+            ) -> ($Single, bool) {
+                const NBITS: u32 = <$Single>::NBITS;
+                // neither the product nor the sum can overflow the double width
+                let prod2 = <$Double>::from(self) * <$Double>::from(mul);
+                let sum2 = (prod2 >> frac_nbits) + <$Double>::from(add);
+                let ans = sum2 as $Single;
+                let overflow = if_signed_unsigned! {
+                    $Signedness,
+                    sum2 >> NBITS != if ans < 0 { -1 } else { 0 },
+                    sum2 >> NBITS != 0
+                };
+                (ans, overflow)
+            }
         }
     };
 }
@@ -590,47 +801,100 @@ impl FallbackHelper for i128 {
     }
 }
 
+trait MulWide: Sized {
+    // Computes the full double-width product as (hi, lo).
+    fn mul_wide(self, rhs: Self) -> (Self, u128);
+}
+
 macro_rules! mul_div_fallback {
     ($Single:ty, $Uns:ty, $Signedness:tt) => {
+        impl MulWide for $Single {
+            #[inline]
+            fn mul_wide(self, rhs: $Single) -> ($Single, $Uns) {
+                let (lh, ll) = self.hi_lo();
+                let (rh, rl) = rhs.hi_lo();
+                let ll_rl = ll.wrapping_mul(rl);
+                let lh_rl = lh.wrapping_mul(rl);
+                let ll_rh = ll.wrapping_mul(rh);
+                let lh_rh = lh.wrapping_mul(rh);
+
+                let col01 = ll_rl as <$Single as FallbackHelper>::Unsigned;
+                let (col01_hi, col01_lo) = col01.hi_lo();
+                let partial_col12 = lh_rl + col01_hi as $Single;
+                let (col12, carry_col3) = FallbackHelper::carrying_add(partial_col12, ll_rh);
+                let (col12_hi, col12_lo) = col12.hi_lo();
+                let ans01 = col12_lo.shift_lo_up_unsigned() + col01_lo;
+                let ans23 = lh_rh + col12_hi + carry_col3.shift_lo_up();
+                (ans23, ans01)
+            }
+        }
+
         impl MulDivOverflow for $Single {
             #[inline]
             fn mul_overflow(self, rhs: $Single, frac_nbits: u32) -> ($Single, bool) {
                 if frac_nbits == 0 {
                     self.overflowing_mul(rhs)
                 } else {
-                    let (lh, ll) = self.hi_lo();
-                    let (rh, rl) = rhs.hi_lo();
-                    let ll_rl = ll.wrapping_mul(rl);
-                    let lh_rl = lh.wrapping_mul(rl);
-                    let ll_rh = ll.wrapping_mul(rh);
-                    let lh_rh = lh.wrapping_mul(rh);
-
-                    let col01 = ll_rl as <$Single as FallbackHelper>::Unsigned;
-                    let (col01_hi, col01_lo) = col01.hi_lo();
-                    let partial_col12 = lh_rl + col01_hi as $Single;
-                    let (col12, carry_col3) = partial_col12.carrying_add(ll_rh);
-                    let (col12_hi, col12_lo) = col12.hi_lo();
-                    let ans01 = col12_lo.shift_lo_up_unsigned() + col01_lo;
-                    let ans23 = lh_rh + col12_hi + carry_col3.shift_lo_up();
+                    let (ans23, ans01) = self.mul_wide(rhs);
                     ans23.combine_lo_then_shl(ans01, frac_nbits)
                 }
             }
 
+            #[inline]
+            fn mul_add_overflow(
+                self,
+                mul: $Single,
+                add: $Single,
+                frac_nbits: u32,
+            ) -> ($Single, bool) {
+                let (hi, lo) = self.mul_wide(mul);
+                let (hi, lo) = match frac_nbits {
+                    0 => (hi, lo),
+                    // shifting twice by 64 extends the sign of signed numbers
+                    128 => (hi >> 64 >> 64, hi as $Uns),
+                    frac => (hi >> frac, (lo >> frac) | ((hi as $Uns) << (128 - frac))),
+                };
+                let add_hi: $Single = if_signed_unsigned! {
+                    $Signedness,
+                    if add < 0 { -1 } else { 0 },
+                    0
+                };
+                let (lo, carry) = lo.overflowing_add(add as $Uns);
+                let hi = hi.wrapping_add(add_hi).wrapping_add(carry as $Single);
+                let ans = lo as $Single;
+                let overflow = if_signed_unsigned! {
+                    $Signedness,
+                    hi != if ans < 0 { -1 } else { 0 },
+                    hi != 0
+                };
+                (ans, overflow)
+            }
+
             #[inline]
             fn div_overflow(self, rhs: $Single, frac_nbits: u32) -> ($Single, bool) {
+                let (quot, overflow, _) = self.div_exact_overflow(rhs, frac_nbits);
+                (quot, overflow)
+            }
+
+            #[inline]
+            fn div_exact_overflow(self, rhs: $Single, frac_nbits: u32) -> ($Single, bool, bool) {
                 if frac_nbits == 0 {
-                    self.overflowing_div(rhs)
+                    let (quot, overflow) = self.overflowing_div(rhs);
+                    (quot, overflow, self.wrapping_rem(rhs) == 0)
                 } else {
                     const NBITS: u32 = <$Single>::NBITS;
-                    let lhs2 = (self >> (NBITS - frac_nbits), (self << frac_nbits) as $Uns);
-                    let (quot2, _) = rhs.div_rem_from(lhs2);
+                    let lhs2 = match frac_nbits {
+                        128 => (self, 0),
+                        frac => (self >> (NBITS - frac), (self << frac) as $Uns),
+                    };
+                    let (quot2, rem) = rhs.div_rem_from(lhs2);
                     let quot = quot2.1 as $Single;
                     let overflow = if_signed_unsigned! {
                         $Signedness,
                         quot2.0 != if quot < 0 { -1 } else { 0 },
                         quot2.0 != 0
                     };
-                    (quot, overflow)
+                    (quot, overflow, rem == 0)
                 }
             }
         }
@@ -648,6 +912,123 @@ mul_div_widen! { i32, i64, Signed }
 mul_div_widen! { i64, i128, Signed }
 mul_div_fallback! { i128, u128, Signed }
 
+// Computes bits - num / den × 2^frac_nbits, rounded to the nearest with ties
+// rounded to even. The bits and the result are 256-bit two's complement
+// numbers stored as (hi, lo). Returns None if den is zero.
+pub(crate) fn sub_rational_wide(
+    bits: (i128, u128),
+    num: i64,
+    den: i64,
+    frac_nbits: u32,
+) -> Option<(i128, u128)> {
+    if den == 0 {
+        return None;
+    }
+    let (num, den) = if den < 0 {
+        (-i128::from(num), -i128::from(den))
+    } else {
+        (i128::from(num), i128::from(den))
+    };
+    // q = floor(num / den × 2^frac_nbits) with 0 <= rem < den; since
+    // |num| < 2^64 and frac_nbits <= 128, q fits in 193 bits.
+    let int = num.div_euclid(den);
+    let mut rem = num.rem_euclid(den);
+    let (mut q_hi, mut q_lo) = (int >> 127, int as u128);
+    for _ in 0..frac_nbits {
+        rem <<= 1;
+        let bit = rem >= den;
+        if bit {
+            rem -= den;
+        }
+        q_hi = q_hi << 1 | (q_lo >> 127) as i128;
+        q_lo = q_lo << 1 | bit as u128;
+    }
+    // The exact answer is (bits - q) - rem / den.
+    let (mut lo, borrow) = bits.1.overflowing_sub(q_lo);
+    let mut hi = bits.0 - q_hi - borrow as i128;
+    let twice_rem = rem * 2;
+    if twice_rem > den || (twice_rem == den && lo & 1 != 0) {
+        let (l, borrow) = lo.overflowing_sub(1);
+        lo = l;
+        hi -= borrow as i128;
+    }
+    Some((hi, lo))
+}
+
+// Computes 2^(2 × frac_nbits) / abs truncated, that is the bits of the
+// reciprocal of a fixed-point number with bits abs. Returns the low 128 bits
+// of the quotient and whether the quotient does not fit in 128 bits. The
+// divisor abs must not be zero.
+pub(crate) fn recip_bits(abs: u128, frac_nbits: u32) -> (u128, bool) {
+    if frac_nbits == 128 {
+        // 2^256 / abs = (2^256 - 1) / abs, plus one if abs divides 2^256
+        let ((_, lo), rem) = abs.div_rem_from((u128::MAX, u128::MAX));
+        let lo = if rem == abs - 1 {
+            lo.wrapping_add(1)
+        } else {
+            lo
+        };
+        return (lo, true);
+    }
+    let shift = frac_nbits * 2;
+    let dividend = if shift >= 128 {
+        (1u128 << (shift - 128), 0)
+    } else {
+        (0, 1u128 << shift)
+    };
+    let ((hi, lo), _) = abs.div_rem_from(dividend);
+    (lo, hi != 0)
+}
+
+// Computes the bits of the square root of a fixed-point number with the
+// given bits and number of fractional bits, rounded to the nearest.
+pub(crate) fn sqrt_bits_round(bits: u128, frac_nbits: u32) -> u128 {
+    // √(bits / 2^frac) × 2^frac = √(bits × 2^frac)
+    let (hi, lo) = match frac_nbits {
+        0 => (0, bits),
+        128 => (bits, 0),
+        frac => (bits >> (128 - frac), bits << frac),
+    };
+    sqrt_wide_round(hi, lo)
+}
+
+// Computes the square root of hi × 2^128 + lo rounded to the nearest. The
+// root must fit in u128. The remainder is kept as a 256-bit number
+// (rem_hi, rem_lo), as it can be up to twice the root.
+fn sqrt_wide_round(hi: u128, lo: u128) -> u128 {
+    let nbits = if hi != 0 {
+        256 - hi.leading_zeros()
+    } else {
+        128 - lo.leading_zeros()
+    };
+    let mut root = 0u128;
+    let (mut rem_hi, mut rem_lo) = (0u128, 0u128);
+    for pair in (0..nbits / 2 + nbits % 2).rev() {
+        let shift = pair * 2;
+        let next = if shift >= 128 {
+            (hi >> (shift - 128)) & 3
+        } else {
+            (lo >> shift) & 3
+        };
+        rem_hi = rem_hi << 2 | rem_lo >> 126;
+        rem_lo = rem_lo << 2 | next;
+        // trial = 4 × root + 1
+        let (trial_hi, trial_lo) = (root >> 126, root << 2 | 1);
+        root <<= 1;
+        if (rem_hi, rem_lo) >= (trial_hi, trial_lo) {
+            let (diff_lo, borrow) = rem_lo.overflowing_sub(trial_lo);
+            rem_hi = rem_hi - trial_hi - borrow as u128;
+            rem_lo = diff_lo;
+            root |= 1;
+        }
+    }
+    // n = root² + rem, and n ≥ (root + 1/2)² ⇔ rem > root since n is an integer
+    if (rem_hi, rem_lo) > (0, root) {
+        root += 1;
+    }
+    root
+}
+
 #[cfg(test)]
 #[allow(clippy::cognitive_complexity)]
 mod tests {
@@ -888,4 +1269,292 @@ mod tests {
         assert_eq!(i0(0.25) % 1, i0(0.25));
         assert_eq!(i0(0.25).rem_euclid_int(1), i0(0.25));
     }
+
+    #[test]
+    fn rational_error() {
+        use crate::types::{I0F128, I64F64, U0F128, U0F8, U128F0, U8F8};
+
+        let third = U0F8::from_num(1.0 / 3.0);
+        assert_eq!(third, U0F8::from_bits(85));
+        // 85/256 is a third of an ULP below 1/3
+        assert_eq!(third.rational_error(1, 3), U0F8::from_num(0));
+        assert_eq!(U0F8::from_bits(84).checked_rational_error(1, 3), None);
+        assert_eq!(U0F8::from_bits(86).rational_error(1, 3), U0F8::from_bits(1));
+        assert_eq!(
+            U0F8::from_bits(86).rational_error(-1, -3),
+            U0F8::from_bits(1)
+        );
+        assert_eq!(third.checked_rational_error(1, 0), None);
+        // ties are rounded to even
+        assert_eq!(
+            U8F8::from_bits(2).rational_error(3, 512),
+            U8F8::from_bits(0)
+        );
+        assert_eq!(
+            U8F8::from_bits(3).rational_error(3, 512),
+            U8F8::from_bits(2)
+        );
+
+        let third = U0F128::from_bits(u128::MAX / 3);
+        assert_eq!(third.rational_error(1, 3), U0F128::from_num(0));
+        let above = U0F128::from_bits(u128::MAX / 3 + 1);
+        assert_eq!(above.rational_error(1, 3), U0F128::from_bits(1));
+        assert_eq!(U0F128::max_value().checked_rational_error(1, 1), None);
+        assert_eq!(
+            U128F0::max_value().rational_error(i64::MAX, 1),
+            U128F0::max_value() - U128F0::from_num(i64::MAX)
+        );
+        assert_eq!(
+            I0F128::min_value().rational_error(-1, 2),
+            I0F128::from_num(0)
+        );
+        assert_eq!(I0F128::min_value().checked_rational_error(1, 2), None);
+        assert_eq!(
+            I64F64::from_num(-5).checked_rational_error(i64::MIN, -1),
+            None
+        );
+        assert_eq!(
+            I64F64::from_num(5).rational_error(i64::MIN, i64::MIN),
+            I64F64::from_num(4)
+        );
+    }
+
+    #[test]
+    fn sum_product() {
+        use crate::types::{I16F16, U8F8};
+
+        let values = [
+            U8F8::from_num(1.5),
+            U8F8::from_num(0.25),
+            U8F8::from_num(100),
+            U8F8::from_bits(1),
+        ];
+        let sum = U8F8::from_num(101.75) + U8F8::from_bits(1);
+        assert_eq!(values.iter().copied().sum::<U8F8>(), sum);
+        assert_eq!(values.iter().sum::<U8F8>(), sum);
+        assert_eq!(values[..0].iter().sum::<U8F8>(), 0);
+
+        let values = [
+            I16F16::from_num(-1.5),
+            I16F16::from_num(4),
+            I16F16::from_num(0.25),
+        ];
+        assert_eq!(values.iter().copied().product::<I16F16>(), -1.5);
+        assert_eq!(values.iter().product::<I16F16>(), -1.5);
+        assert_eq!(values[..0].iter().product::<I16F16>(), 1);
+    }
+
+    #[test]
+    fn mul_add() {
+        use crate::types::{I0F128, I16F16, I64F64, I8F8, U0F8, U128F0, U64F64, U8F8};
+
+        for &(a, b, c) in &[(1.5, 2.25, -0.75), (-3.0, 0.125, 10.0), (100.0, -0.5, 0.0)] {
+            let expected = I16F16::from_num(a * b + c);
+            let (a, b, c) = (
+                I16F16::from_num(a),
+                I16F16::from_num(b),
+                I16F16::from_num(c),
+            );
+            assert_eq!(a.mul_add(b, c), expected);
+            assert_eq!(a.mul_add(b, c), a * b + c);
+            let (a, b, c) = (
+                I64F64::from_num(a),
+                I64F64::from_num(b),
+                I64F64::from_num(c),
+            );
+            assert_eq!(a.mul_add(b, c), I64F64::from_num(expected));
+        }
+
+        // the product overflows, but the result fits
+        let (a, b) = (I8F8::from_num(100), I8F8::from_num(2));
+        assert_eq!(a.checked_mul(b), None);
+        assert_eq!(
+            a.checked_mul_add(b, I8F8::from_num(-100)),
+            Some(I8F8::from_num(100))
+        );
+        assert_eq!(a.checked_mul_add(b, I8F8::from_num(-50)), None);
+        let (a, b) = (I64F64::from_num(1u64 << 40), I64F64::from_num(1u64 << 23));
+        assert_eq!(a.checked_mul(b), None);
+        assert_eq!(
+            a.checked_mul_add(b, I64F64::min_value()),
+            Some(I64F64::from_num(0))
+        );
+        assert_eq!(
+            a.checked_mul_add(b, -I64F64::DELTA),
+            Some(I64F64::max_value())
+        );
+        assert_eq!(a.checked_mul_add(b, I64F64::from_num(0)), None);
+        assert_eq!(
+            U128F0::max_value().checked_mul_add(U128F0::from_num(1), U128F0::from_num(1)),
+            None
+        );
+        let big = U128F0::from_num(1u128 << 64);
+        assert_eq!(big.checked_mul_add(big, U128F0::from_num(0)), None);
+
+        // the product is truncated like multiplication
+        let a = U8F8::from_bits(3);
+        assert_eq!(
+            a.mul_add(U8F8::from_num(0.5), U8F8::from_num(1)),
+            U8F8::from_bits(257)
+        );
+        let a = U64F64::from_bits(3);
+        assert_eq!(
+            a.mul_add(U64F64::from_num(0.5), U64F64::from_num(1)),
+            U64F64::from_bits((1 << 64) + 1)
+        );
+        let a = I0F128::from_bits(-3);
+        assert_eq!(
+            a.mul_add(I0F128::from_num(0.25), I0F128::max_value()),
+            I0F128::max_value() - I0F128::DELTA
+        );
+        assert_eq!(
+            U0F8::from_num(0.5).mul_add(U0F8::from_num(0.5), U0F8::from_num(0.5)),
+            0.75
+        );
+    }
+
+    #[test]
+    fn lerp() {
+        use crate::types::{I0F32, I16F16, I8F8, U0F8, U16F16, U64F64};
+
+        let (a, b) = (I16F16::from_num(0), I16F16::from_num(10));
+        assert_eq!(a.lerp(b, I16F16::from_num(0.5)), 5);
+        assert_eq!(b.lerp(a, I16F16::from_num(0.5)), 5);
+        assert_eq!(a.lerp(b, I16F16::from_num(-0.5)), -5);
+        for &(a, b) in &[(-3.25, 7.5), (7.5, -3.25), (2.0, 2.0)] {
+            let (a, b) = (I16F16::from_num(a), I16F16::from_num(b));
+            assert_eq!(a.lerp(b, I16F16::from_num(0)), a);
+            assert_eq!(a.lerp(b, I16F16::from_num(1)), b);
+            for &t in &[0.125, 0.3, 0.9] {
+                let expected = a.to_num::<f64>() + (b - a).to_num::<f64>() * t;
+                let ans = a.lerp(b, I16F16::from_num(t)).to_num::<f64>();
+                assert!((ans - expected).abs() < 1e-4);
+            }
+        }
+
+        // the difference does not fit, but the result does
+        let (min, max) = (I8F8::min_value(), I8F8::max_value());
+        assert_eq!(min.lerp(max, I8F8::from_num(0)), min);
+        assert_eq!(min.lerp(max, I8F8::from_num(1)), max);
+        assert_eq!(max.lerp(min, I8F8::from_num(1)), min);
+        assert_eq!(min.lerp(max, I8F8::from_num(0.5)), I8F8::from_bits(-1));
+        assert_eq!(min.checked_lerp(max, I8F8::from_num(1.5)), None);
+        assert_eq!(max.checked_lerp(min, I8F8::from_num(1.5)), None);
+        assert_eq!(max.checked_lerp(min, I8F8::from_num(-0.5)), None);
+        let (lo, hi) = (I0F32::from_num(-0.25), I0F32::from_num(0.25));
+        assert_eq!(lo.lerp(hi, I0F32::from_num(-0.5)), -0.5);
+        assert_eq!(
+            lo.checked_lerp(hi, I0F32::from_num(0.25)),
+            Some(I0F32::from_num(-0.125))
+        );
+
+        let (a, b) = (U16F16::from_num(10), U16F16::from_num(2));
+        assert_eq!(a.lerp(b, U16F16::from_num(0.25)), 8);
+        assert_eq!(b.lerp(a, U16F16::from_num(0.25)), 4);
+        assert_eq!(
+            a.checked_lerp(b, U16F16::from_num(1.25)),
+            Some(U16F16::from_num(0))
+        );
+        assert_eq!(a.checked_lerp(b, U16F16::from_num(2)), None);
+        let (a, b) = (U64F64::from_num(0), U64F64::max_value());
+        assert_eq!(a.lerp(b, U64F64::from_num(1)), b);
+        assert_eq!(b.lerp(a, U64F64::from_num(1)), a);
+        assert_eq!(
+            U0F8::from_num(0).lerp(U0F8::max_value(), U0F8::from_num(0.5)),
+            127.0 / 256.0
+        );
+    }
+
+    #[test]
+    fn wide_mul() {
+        use crate::types::{I0F64, I16F16, I32F32, I64F64, U0F8, U32F32, U8F8};
+
+        let prod = I16F16::from_num(-2.75).wide_mul(I16F16::from_num(1.5));
+        assert_eq!(prod, I32F32::from_num(-4.125));
+        let tiny = I16F16::from_bits(1);
+        assert_eq!(tiny.wide_mul(tiny), I32F32::from_bits(1));
+        assert_eq!(
+            I16F16::min_value().wide_mul(I16F16::min_value()),
+            I32F32::from_num(1u64 << 30)
+        );
+        let max = U8F8::max_value();
+        assert_eq!(max.wide_mul(max).to_bits(), 0xFFFF * 0xFFFF);
+        assert_eq!(
+            U0F8::max_value().wide_mul(U0F8::from_num(0.5)).to_bits(),
+            0xFF * 0x80
+        );
+
+        // compare with exact 128-bit products
+        let vals = [
+            i64::MIN,
+            i64::MIN + 1,
+            -0x1234_5678_9ABC_DEF0,
+            -1,
+            0,
+            1,
+            0x0FED_CBA9_8765_4321,
+            i64::MAX,
+        ];
+        for &a in &vals {
+            for &b in &vals {
+                let prod = I32F32::from_bits(a).wide_mul(I32F32::from_bits(b));
+                assert_eq!(prod, I64F64::from_bits(i128::from(a) * i128::from(b)));
+                let prod = I0F64::from_bits(a).wide_mul(I0F64::from_bits(b));
+                assert_eq!(prod.to_bits(), i128::from(a) * i128::from(b));
+                let (ua, ub) = (a as u64, b as u64);
+                let prod = U32F32::from_bits(ua).wide_mul(U32F32::from_bits(ub));
+                assert_eq!(prod.to_bits(), u128::from(ua) * u128::from(ub));
+            }
+        }
+    }
+
+    #[test]
+    fn int_operand() {
+        use crate::types::{I0F8, I16F16, I1F7, I4F4, U0F8, U8F8};
+
+        let a = I16F16::from_num(1.5);
+        assert_eq!(a + 2, 3.5);
+        assert_eq!(a + -2, -0.5);
+        assert_eq!(a - 2, -0.5);
+        assert_eq!(a - -2, 3.5);
+        assert_eq!(a * 3, 4.5);
+        assert_eq!(a * -3, -4.5);
+        assert_eq!(a / 2, 0.75);
+        assert_eq!(a / -2, -0.75);
+        assert_eq!(-a + 2, 0.5);
+        assert_eq!(-a - 2, -3.5);
+        assert_eq!(I16F16::from_num(-2.75) + 1, -1.75);
+        assert_eq!(I16F16::from_num(-2.75) - -5, 2.25);
+        let mut b = a;
+        b += 5;
+        assert_eq!(b, 6.5);
+        b -= -1;
+        assert_eq!(b, 7.5);
+        b *= -2;
+        assert_eq!(b, -15);
+        b /= 4;
+        assert_eq!(b, -3.75);
+
+        let c = U8F8::from_num(0.25);
+        assert_eq!(c + 7, 7.25);
+        assert_eq!((c + 7) - 7, c);
+        assert_eq!(U8F8::max_value() - 255, U8F8::max_value().frac());
+
+        // the integer does not fit, but the result does
+        assert_eq!(I4F4::from_num(-1) + 8, 7);
+        assert_eq!(I4F4::from_num(-0.5) + 8, 7.5);
+        assert_eq!(I4F4::from_num(1) - 9, -8);
+        assert_eq!(I1F7::from_num(-0.25) + 1, 0.75);
+        assert_eq!(I1F7::from_num(0.25) - 1, -0.75);
+        assert_eq!(I0F8::from_num(-0.25) + 0, -0.25);
+        assert_eq!(U0F8::from_num(0.25) - 0, 0.25);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "overflow")]
+    fn int_operand_overflow() {
+        use crate::types::I4F4;
+        let _ = I4F4::from_num(1) + 7;
+    }
 }
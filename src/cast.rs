@@ -179,6 +179,7 @@ macro_rules! compile_time {
                 for $SrcI<FracSrc>
             {
                 <$DstI<FracDst>>::INT_NBITS >= <$SrcI<FracSrc>>::INT_NBITS
+                    && <$DstI<FracDst>>::FRAC_NBITS >= <$SrcI<FracSrc>>::FRAC_NBITS
             }
         }
 
@@ -195,6 +196,7 @@ macro_rules! compile_time {
                 for $SrcU<FracSrc>
             {
                 <$DstI<FracDst>>::INT_NBITS > <$SrcU<FracSrc>>::INT_NBITS
+                    && <$DstI<FracDst>>::FRAC_NBITS >= <$SrcU<FracSrc>>::FRAC_NBITS
             }
         }
 
@@ -203,6 +205,7 @@ macro_rules! compile_time {
                 for $SrcU<FracSrc>
             {
                 <$DstU<FracDst>>::INT_NBITS >= <$SrcU<FracSrc>>::INT_NBITS
+                    && <$DstU<FracDst>>::FRAC_NBITS >= <$SrcU<FracSrc>>::FRAC_NBITS
             }
         }
     };
@@ -398,3 +401,153 @@ compile_time_float! {
     FixedI8(LeEqU8), FixedI16(LeEqU16), FixedI32(LeEqU32), FixedI64(LeEqU64), FixedI128(LeEqU128),
     FixedU8(LeEqU8), FixedU16(LeEqU16), FixedU32(LeEqU32), FixedU64(LeEqU64), FixedU128(LeEqU128),
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::types::*;
+    use az::{Cast, CheckedCast, OverflowingCast, SaturatingCast, StaticCast, WrappingCast};
+
+    #[test]
+    fn fixed_to_fixed() {
+        macro_rules! check {
+            ($Src:ident -> $Dst:ident) => {{
+                let src = $Src::from_num(1.5);
+                assert_eq!(Cast::<$Dst>::cast(src), $Dst::from_num(src));
+                assert_eq!(
+                    CheckedCast::<$Dst>::checked_cast(src),
+                    $Dst::checked_from_num(src)
+                );
+                assert_eq!(
+                    SaturatingCast::<$Dst>::saturating_cast(src),
+                    $Dst::saturating_from_num(src)
+                );
+                assert_eq!(
+                    WrappingCast::<$Dst>::wrapping_cast(src),
+                    $Dst::wrapping_from_num(src)
+                );
+                assert_eq!(
+                    OverflowingCast::<$Dst>::overflowing_cast(src),
+                    $Dst::overflowing_from_num(src)
+                );
+                let max = $Src::max_value();
+                assert_eq!(
+                    CheckedCast::<$Dst>::checked_cast(max),
+                    $Dst::checked_from_num(max)
+                );
+                assert_eq!(
+                    SaturatingCast::<$Dst>::saturating_cast(max),
+                    $Dst::saturating_from_num(max)
+                );
+                assert_eq!(
+                    WrappingCast::<$Dst>::wrapping_cast(max),
+                    $Dst::wrapping_from_num(max)
+                );
+                assert_eq!(
+                    OverflowingCast::<$Dst>::overflowing_cast(max),
+                    $Dst::overflowing_from_num(max)
+                );
+                let min = $Src::min_value();
+                assert_eq!(
+                    CheckedCast::<$Dst>::checked_cast(min),
+                    $Dst::checked_from_num(min)
+                );
+                assert_eq!(
+                    SaturatingCast::<$Dst>::saturating_cast(min),
+                    $Dst::saturating_from_num(min)
+                );
+                assert_eq!(
+                    OverflowingCast::<$Dst>::overflowing_cast(min),
+                    $Dst::overflowing_from_num(min)
+                );
+            }};
+        }
+        // bit growth
+        check!(I8F8 -> I16F16);
+        check!(U8F8 -> I32F32);
+        check!(I4F4 -> U64F64);
+        check!(U16F16 -> I64F64);
+        // bit shrink
+        check!(I16F16 -> I8F8);
+        check!(I64F64 -> U4F4);
+        check!(U32F32 -> I8F8);
+        check!(I64F64 -> I16F16);
+
+        let f = I16F16::from_num(300.25);
+        assert_eq!(CheckedCast::<I8F8>::checked_cast(f), None);
+        assert_eq!(f.checked_cast(), Some(I32F32::from_num(300.25)));
+        assert_eq!(
+            SaturatingCast::<I8F8>::saturating_cast(f),
+            I8F8::max_value()
+        );
+        assert_eq!(
+            SaturatingCast::<I8F8>::saturating_cast(-f),
+            I8F8::min_value()
+        );
+        assert_eq!(
+            OverflowingCast::<I8F8>::overflowing_cast(f),
+            (I8F8::from_num(44.25), true)
+        );
+        assert_eq!(
+            WrappingCast::<U8F8>::wrapping_cast(-f),
+            U8F8::from_num(211.75)
+        );
+        // fractional bits are truncated like from_num
+        assert_eq!(
+            Cast::<U4F4>::cast(U8F8::from_num(1.99)),
+            U4F4::from_num(1.9375)
+        );
+    }
+
+    #[test]
+    fn static_fixed_to_fixed() {
+        for &f in &[0.0f64, 1.5, -2.25, 32767.999_984_741_2, -32768.0] {
+            let a = I16F16::from_num(f);
+            let b = StaticCast::<I32F32>::static_cast(a).unwrap();
+            assert_eq!(b, I32F32::from_num(a));
+            assert_eq!(StaticCast::<I16F16>::static_cast(b), None);
+        }
+        // widening the integer part alone loses fractional bits
+        assert_eq!(
+            StaticCast::<I32F0>::static_cast(I16F16::from_num(1.5)),
+            None
+        );
+        // widening the fractional part alone loses integer range
+        assert_eq!(
+            StaticCast::<I8F24>::static_cast(I16F16::from_num(1.5)),
+            None
+        );
+        assert_eq!(
+            StaticCast::<I32F32>::static_cast(U16F16::from_num(1.5)),
+            Some(I32F32::from_num(1.5))
+        );
+        assert_eq!(
+            StaticCast::<I16F16>::static_cast(U16F16::from_num(1.5)),
+            None
+        );
+        assert_eq!(
+            StaticCast::<U32F32>::static_cast(I16F16::from_num(1.5)),
+            None
+        );
+    }
+
+    #[test]
+    fn from_bool() {
+        assert_eq!(Cast::<U4F4>::cast(true), 1);
+        assert_eq!(Cast::<I8F8>::cast(false), 0);
+        assert_eq!(CheckedCast::<U0F8>::checked_cast(true), None);
+        assert_eq!(
+            SaturatingCast::<U0F8>::saturating_cast(true),
+            U0F8::max_value()
+        );
+        assert_eq!(WrappingCast::<I1F7>::wrapping_cast(true), I1F7::min_value());
+        assert_eq!(
+            OverflowingCast::<I1F7>::overflowing_cast(true),
+            (I1F7::min_value(), true)
+        );
+        assert_eq!(
+            StaticCast::<U1F7>::static_cast(true),
+            Some(U1F7::from_num(1))
+        );
+        assert_eq!(StaticCast::<I1F7>::static_cast(true), None);
+    }
+}
@@ -24,6 +24,21 @@ use core::cmp::Ordering;
 #[cfg(feature = "f16")]
 use half::{bf16, f16};
 
+// Converts the widest bits to the bits of the destination. A positive
+// value that only fits in the unsigned range wraps to negative for a
+// signed destination, and is reported as an overflow.
+macro_rules! conv_bits {
+    ($bits:expr, $Bits:ty) => {
+        match $bits {
+            Widest::Unsigned(bits) => {
+                let bits = bits as $Bits;
+                (bits, bits.is_negative())
+            }
+            Widest::Negative(bits) => (bits as $Bits, false),
+        }
+    };
+}
+
 macro_rules! fixed_cmp_fixed {
     ($Lhs:ident($LhsLeEqU:ident), $Rhs:ident($RhsLeEqU:ident)) => {
         impl<FracLhs: $LhsLeEqU, FracRhs: $RhsLeEqU> PartialEq<$Rhs<FracRhs>> for $Lhs<FracLhs> {
@@ -34,11 +49,11 @@ macro_rules! fixed_cmp_fixed {
                     Self::FRAC_NBITS,
                     Self::INT_NBITS,
                 );
-                let rhs_bits = match conv.bits {
-                    Widest::Unsigned(bits) => bits as <Self as Fixed>::Bits,
-                    Widest::Negative(bits) => bits as <Self as Fixed>::Bits,
-                };
-                conv.dir == Ordering::Equal && !conv.overflow && rhs_bits == self.to_bits()
+                let (rhs_bits, rhs_overflow) = conv_bits!(conv.bits, <Self as Fixed>::Bits);
+                conv.dir == Ordering::Equal
+                    && !conv.overflow
+                    && !rhs_overflow
+                    && rhs_bits == self.to_bits()
             }
         }
 
@@ -55,17 +70,14 @@ macro_rules! fixed_cmp_fixed {
                     Self::FRAC_NBITS,
                     Self::INT_NBITS,
                 );
-                if conv.overflow {
+                let (rhs_bits, rhs_overflow) = conv_bits!(conv.bits, <Self as Fixed>::Bits);
+                if conv.overflow || rhs_overflow {
                     return if rhs.to_bits().is_negative() {
                         Some(Ordering::Greater)
                     } else {
                         Some(Ordering::Less)
                     };
                 }
-                let rhs_bits = match conv.bits {
-                    Widest::Unsigned(bits) => bits as <Self as Fixed>::Bits,
-                    Widest::Negative(bits) => bits as <Self as Fixed>::Bits,
-                };
                 Some(self.to_bits().cmp(&rhs_bits).then(conv.dir))
             }
 
@@ -81,13 +93,10 @@ macro_rules! fixed_cmp_fixed {
                     Self::FRAC_NBITS,
                     Self::INT_NBITS,
                 );
-                if conv.overflow {
+                let (rhs_bits, rhs_overflow) = conv_bits!(conv.bits, <Self as Fixed>::Bits);
+                if conv.overflow || rhs_overflow {
                     return !rhs.to_bits().is_negative();
                 }
-                let rhs_bits = match conv.bits {
-                    Widest::Unsigned(bits) => bits as <Self as Fixed>::Bits,
-                    Widest::Negative(bits) => bits as <Self as Fixed>::Bits,
-                };
                 self.to_bits() < rhs_bits
                     || (self.to_bits() == rhs_bits && conv.dir == Ordering::Less)
             }
@@ -191,11 +200,11 @@ macro_rules! fixed_cmp_float {
                     FloatKind::Finite { conv, .. } => conv,
                     _ => return false,
                 };
-                let rhs_bits = match conv.bits {
-                    Widest::Unsigned(bits) => bits as <Self as Fixed>::Bits,
-                    Widest::Negative(bits) => bits as <Self as Fixed>::Bits,
-                };
-                conv.dir == Ordering::Equal && !conv.overflow && rhs_bits == self.to_bits()
+                let (rhs_bits, rhs_overflow) = conv_bits!(conv.bits, <Self as Fixed>::Bits);
+                conv.dir == Ordering::Equal
+                    && !conv.overflow
+                    && !rhs_overflow
+                    && rhs_bits == self.to_bits()
             }
         }
 
@@ -226,17 +235,14 @@ macro_rules! fixed_cmp_float {
                     (true, false) => return Some(Ordering::Less),
                     _ => {}
                 }
-                if conv.overflow {
+                let (rhs_bits, rhs_overflow) = conv_bits!(conv.bits, <Self as Fixed>::Bits);
+                if conv.overflow || rhs_overflow {
                     return if rhs_is_neg {
                         Some(Ordering::Greater)
                     } else {
                         Some(Ordering::Less)
                     };
                 }
-                let rhs_bits = match conv.bits {
-                    Widest::Unsigned(bits) => bits as <Self as Fixed>::Bits,
-                    Widest::Negative(bits) => bits as <Self as Fixed>::Bits,
-                };
                 Some(self.to_bits().cmp(&rhs_bits).then(conv.dir))
             }
 
@@ -254,13 +260,10 @@ macro_rules! fixed_cmp_float {
                     (true, false) => return true,
                     _ => {}
                 }
-                if conv.overflow {
+                let (rhs_bits, rhs_overflow) = conv_bits!(conv.bits, <Self as Fixed>::Bits);
+                if conv.overflow || rhs_overflow {
                     return !rhs_is_neg;
                 }
-                let rhs_bits = match conv.bits {
-                    Widest::Unsigned(bits) => bits as <Self as Fixed>::Bits,
-                    Widest::Negative(bits) => bits as <Self as Fixed>::Bits,
-                };
                 let lhs_bits = self.to_bits();
                 lhs_bits < rhs_bits || (lhs_bits == rhs_bits && conv.dir == Ordering::Less)
             }
@@ -301,13 +304,10 @@ macro_rules! fixed_cmp_float {
                     (true, false) => return true,
                     _ => {}
                 }
-                if conv.overflow {
+                let (lhs_bits, lhs_overflow) = conv_bits!(conv.bits, <$Fix<Frac> as Fixed>::Bits);
+                if conv.overflow || lhs_overflow {
                     return lhs_is_neg;
                 }
-                let lhs_bits = match conv.bits {
-                    Widest::Unsigned(bits) => bits as <$Fix<Frac> as Fixed>::Bits,
-                    Widest::Negative(bits) => bits as <$Fix<Frac> as Fixed>::Bits,
-                };
                 let rhs_bits = rhs.to_bits();
                 lhs_bits < rhs_bits || (lhs_bits == rhs_bits && conv.dir == Ordering::Greater)
             }
@@ -509,4 +509,107 @@ mod tests {
         assert_eq!(a, 1i32 << 12);
         assert_eq!(b, 0);
     }
+
+    #[test]
+    fn cmp_signed_beyond_max() {
+        use core::cmp::Ordering::*;
+        use types::{I16F16, I8F8, U8F8};
+        // 128 fits in the unsigned but not the signed range of I8F8
+        let one = I8F8::from_num(1);
+        let min = I8F8::min_value();
+        assert_eq!(one.partial_cmp(&128.0f64), Some(Less));
+        assert_eq!(one.partial_cmp(&200.0f32), Some(Less));
+        assert!(one < 200.0f32);
+        assert!(200.0f32 > one);
+        assert_ne!(min, 128.0f64);
+        assert_ne!(128.0f64, min);
+        assert_eq!(128.0f64.partial_cmp(&one), Some(Greater));
+        assert_eq!(one.partial_cmp(&128u8), Some(Less));
+        assert!(one < 200u8);
+        assert_ne!(min, 128u8);
+        let big = U8F8::from_num(200);
+        assert_eq!(one.partial_cmp(&big), Some(Less));
+        assert_eq!(big.partial_cmp(&one), Some(Greater));
+        assert!(one < big);
+        assert!(big > one);
+        assert_ne!(min, U8F8::from_num(128));
+        let max = I16F16::max_value();
+        assert_eq!(max.partial_cmp(&32768.0f32), Some(Less));
+        assert!(max < 32768.0f32);
+        assert!(max < 32768.0f64 && max > 32767.0f64);
+    }
+
+    #[test]
+    fn cmp_different_frac() {
+        use core::cmp::Ordering::*;
+        use types::{I0F32, I16F16, I8F8, U0F8, U4F4, U8F8};
+        let half_8 = I8F8::from_num(0.5);
+        let half_16 = I16F16::from_num(0.5);
+        // same value, different bit patterns
+        assert_eq!(half_8.to_bits(), 0x80);
+        assert_eq!(half_16.to_bits(), 0x8000);
+        assert_eq!(half_8, half_16);
+        assert_eq!(half_16, half_8);
+        assert_eq!(half_8.partial_cmp(&half_16), Some(Equal));
+        assert_eq!(U0F8::from_num(0.25), I0F32::from_num(0.25));
+        assert_eq!(U4F4::from_num(-0.0), I16F16::from_num(0));
+
+        // I16F16 can hold values between the I8F8 steps
+        let between = I16F16::from_num(0.5) + I16F16::from_bits(1);
+        assert_ne!(half_8, between);
+        assert!(half_8 < between);
+        assert!(between > half_8);
+        assert_eq!(between.partial_cmp(&half_8), Some(Greater));
+        assert!(I8F8::from_num(-0.75) < I16F16::from_num(-0.5));
+        assert!(I16F16::from_num(200) > I8F8::max_value());
+        assert!(I16F16::from_num(-200) < I8F8::min_value());
+        assert!(U8F8::from_num(200) > I8F8::max_value());
+        assert!(I8F8::from_num(-1) < U8F8::from_num(0));
+    }
+
+    #[test]
+    fn cmp_primitives() {
+        use core::cmp::Ordering::*;
+        use types::{I16F16, U0F32, U8F8};
+        let a = I16F16::from_num(3.25);
+        assert!(a > 3);
+        assert!(3 < a);
+        assert!(a < 4u128);
+        assert!(4u128 > a);
+        assert!(a > -4i8 && a >= 0usize);
+        assert_ne!(a, 3i32);
+        assert_eq!(I16F16::from_num(3), 3u64);
+        assert_eq!(a.partial_cmp(&3i64), Some(Greater));
+        assert_eq!(4isize.partial_cmp(&a), Some(Greater));
+        assert!(I16F16::from_num(-1) < 0u8);
+        assert!(I16F16::from_num(-1) < u128::MAX);
+        assert!(I16F16::from_num(-1) > i128::MIN);
+
+        let b = U8F8::from_num(2.5);
+        assert_eq!(b, 2.5f64);
+        assert_eq!(2.5f32, b);
+        assert_ne!(b, 2.5f64 + 2.0 * f64::EPSILON);
+        assert!(b < 2.5f64 + 2.0 * f64::EPSILON);
+
+        // 1 - 2^-32 rounds to 1.0 when converted to f32, but the
+        // comparison uses the exact value of the float
+        let c = U0F32::from_bits(u32::MAX);
+        assert_eq!(c.to_num::<f32>(), 1.0);
+        assert_ne!(c, 1.0f32);
+        assert!(c < 1.0f32);
+        assert!(1.0f32 > c);
+        assert_eq!(c.partial_cmp(&1.0f32), Some(Less));
+        assert_eq!(c, 1.0f64 - (-32f64).exp2());
+
+        for &nan in &[f32::NAN, -f32::NAN] {
+            assert_ne!(b, nan);
+            assert_ne!(nan, b);
+            assert_eq!(b.partial_cmp(&nan), None);
+            assert_eq!(nan.partial_cmp(&b), None);
+            assert!(!b.lt(&nan) && !b.le(&nan) && !b.gt(&nan) && !b.ge(&nan));
+            assert!(!nan.lt(&b) && !nan.le(&b) && !nan.gt(&b) && !nan.ge(&b));
+        }
+        assert!(b < f64::INFINITY && b > f64::NEG_INFINITY);
+        assert!(f64::NEG_INFINITY < b);
+    }
 }
@@ -29,9 +29,33 @@ assert_eq!(format!("{:.8b}", tau), "110.01001000");
 println!("τ = 2π with six decimal places is {:.8}", tau);
 assert_eq!(format!("{:.8}", tau), "6.28318531");
 ```
+
+Some of the constants are also available as associated constants of
+the fixed-point types, for example [`I16F16::PI`]. These are rounded
+to the nearest at the precision of the type, and are only available
+when the type has enough integer bits to hold the constant.
+
+```rust
+use substrate_fixed::types::{I16F16, I4F28, U0F32};
+assert_eq!(I16F16::PI, I16F16::from_bits(0x0003_243F));
+assert_eq!(I4F28::TAU.to_num::<f32>(), 6.283_185_3);
+assert_eq!(U0F32::LN_2, U0F32::from_bits(0xB172_17F8));
+```
+
+[`I16F16::PI`]: ../struct.FixedI32.html#associatedconstant.PI
 */
 
-use crate::types::{U0F128, U1F127, U2F126, U3F125};
+use crate::{
+    types::{
+        extra::{
+            IsLessOrEqual, True, Unsigned, U12, U124, U125, U126, U127, U128, U13, U14, U15, U16,
+            U28, U29, U30, U31, U32, U4, U5, U6, U60, U61, U62, U63, U64, U7, U8,
+        },
+        U0F128, U1F127, U2F126, U3F125,
+    },
+    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
+    FixedU8,
+};
 
 /// τ = 6.28318…
 // TAU = 6.283185307179586476925286766559005768394...
@@ -145,6 +169,77 @@ pub const LN_2: U0F128 = U0F128::from_bits(0xB172_17F7_D1CF_79AB_C9E3_B398_03F2_
 // LN_10 = 2.302585092994045684017991454684364207601...
 pub const LN_10: U2F126 = U2F126::from_bits(0x935D_8DDD_AAA8_AC16_EA56_D62B_82D3_0A28);
 
+// Shifts right bits with frac_nbits fractional bits so that the result has
+// to_frac_nbits fractional bits, rounding to the nearest.
+const fn round_to_frac(bits: u128, frac_nbits: u32, to_frac_nbits: u32) -> u128 {
+    let shift = frac_nbits - to_frac_nbits;
+    if shift == 0 {
+        bits
+    } else if shift >= 128 {
+        // only the rounding bit is left
+        (bits >> 127) & 1
+    } else {
+        (bits >> shift) + ((bits >> (shift - 1)) & 1)
+    }
+}
+
+macro_rules! assoc_consts {
+    ($Fixed:ident($Inner:ty); $Bound:ty; $($NAME:ident: $desc:expr, $src:ident($src_frac:expr);)*) => {
+        impl<Frac: Unsigned + IsLessOrEqual<$Bound, Output = True>> $Fixed<Frac> {
+            $(
+                comment! {
+                    $desc,
+                    ", rounded to the nearest.
+
+This constant is only available when there are enough integer bits to
+hold it.
+";
+                    pub const $NAME: $Fixed<Frac> = $Fixed::from_bits(
+                        round_to_frac($src.to_bits(), $src_frac, Frac::U32) as $Inner
+                    );
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! fixed_consts {
+    ($Fixed:ident($Inner:ty); $Int0:ty, $Int1:ty, $Int2:ty, $Int3:ty) => {
+        assoc_consts! {
+            $Fixed($Inner); $Int0;
+            LN_2: "ln 2 = 0.693147…", LN_2(128);
+            FRAC_1_SQRT_2: "1/√2 = 0.707106…", FRAC_1_SQRT_2(128);
+        }
+        assoc_consts! {
+            $Fixed($Inner); $Int1;
+            SQRT_2: "√2 = 1.41421…", SQRT_2(127);
+        }
+        assoc_consts! {
+            $Fixed($Inner); $Int2;
+            PI: "π = 3.14159…", PI(126);
+            E: "e = 2.71828…", E(126);
+            LN_10: "ln 10 = 2.30258…", LN_10(126);
+        }
+        assoc_consts! {
+            $Fixed($Inner); $Int3;
+            TAU: "τ = 6.28318…", TAU(125);
+        }
+    };
+}
+
+// The bounds are the largest numbers of fractional bits that leave 0, 1, 2
+// and 3 integer bits, not counting the sign bit of signed numbers.
+fixed_consts! { FixedI8(i8); U7, U6, U5, U4 }
+fixed_consts! { FixedI16(i16); U15, U14, U13, U12 }
+fixed_consts! { FixedI32(i32); U31, U30, U29, U28 }
+fixed_consts! { FixedI64(i64); U63, U62, U61, U60 }
+fixed_consts! { FixedI128(i128); U127, U126, U125, U124 }
+fixed_consts! { FixedU8(u8); U8, U7, U6, U5 }
+fixed_consts! { FixedU16(u16); U16, U15, U14, U13 }
+fixed_consts! { FixedU32(u32); U32, U31, U30, U29 }
+fixed_consts! { FixedU64(u64); U64, U63, U62, U61 }
+fixed_consts! { FixedU128(u128); U128, U127, U126, U125 }
+
 #[cfg(test)]
 #[allow(clippy::cognitive_complexity, clippy::float_cmp)]
 mod tests {
@@ -338,4 +433,58 @@ mod tests {
         compare_parse(LN_2, "0.6931471805599453094172321214581765680755");
         compare_parse(LN_10, "2.302585092994045684017991454684364207601");
     }
+
+    #[test]
+    fn assoc_consts() {
+        use crate::types::{
+            I16F16, I1F31, I1F7, I2F62, I3F29, I4F124, I8F0, U0F128, U0F16, U0F32, U128F0, U16F0,
+            U1F15, U2F30, U3F61, U8F8,
+        };
+
+        fn check<F: Fixed>(val: F, expected: f64)
+        where
+            F::Bits: TryFrom<u8>,
+        {
+            let delta = f64::from_fixed(F::from_bits(F::Bits::try_from(1).ok().unwrap()));
+            assert!((val.to_num::<f64>() - expected).abs() <= delta / 2.0);
+        }
+
+        check(I16F16::PI, f64::consts::PI);
+        check(I16F16::TAU, f64::consts::PI * 2.0);
+        check(I16F16::E, f64::consts::E);
+        check(I16F16::LN_2, f64::consts::LN_2);
+        check(I16F16::LN_10, f64::consts::LN_10);
+        check(I16F16::SQRT_2, f64::consts::SQRT_2);
+        check(I16F16::FRAC_1_SQRT_2, f64::consts::FRAC_1_SQRT_2);
+        check(U8F8::PI, f64::consts::PI);
+        check(I1F7::LN_2, f64::consts::LN_2);
+        check(U0F16::FRAC_1_SQRT_2, f64::consts::FRAC_1_SQRT_2);
+        check(U1F15::SQRT_2, f64::consts::SQRT_2);
+        check(I1F31::LN_2, f64::consts::LN_2);
+        check(U2F30::E, f64::consts::E);
+        check(I3F29::PI, f64::consts::PI);
+        check(U3F61::TAU, f64::consts::PI * 2.0);
+        check(I2F62::SQRT_2, f64::consts::SQRT_2);
+        check(I8F0::LN_2, f64::consts::LN_2);
+        check(I8F0::PI, f64::consts::PI);
+        check(U16F0::FRAC_1_SQRT_2, f64::consts::FRAC_1_SQRT_2);
+        check(U16F0::TAU, f64::consts::PI * 2.0);
+        check(U128F0::LN_2, f64::consts::LN_2);
+        check(U128F0::E, f64::consts::E);
+
+        // at full precision the constants round the truncated values
+        assert_eq!(U0F128::LN_2, LN_2);
+        // the last bit of TAU is one, so dropping it rounds up
+        assert_eq!(I4F124::TAU, I4F124::from_num(TAU) + I4F124::DELTA);
+        // 0xB172_17F7... rounds down to 0xB172 and up to 0xB172_17F8
+        assert_eq!(U0F16::LN_2.to_bits(), 0xB172);
+        assert_eq!(U0F32::LN_2.to_bits(), 0xB172_17F8);
+        // with no fractional bits only the rounding bit is left
+        assert_eq!(I8F0::LN_2, 1);
+        assert_eq!(U16F0::FRAC_1_SQRT_2, 1);
+        assert_eq!(U128F0::LN_2, 1);
+        const CONSTS: [I16F16; 2] = [I16F16::PI, I16F16::E];
+        assert_eq!(CONSTS[0].to_bits(), 0x0003_243F);
+        assert_eq!(CONSTS[1].to_bits(), 0x0002_B7E1);
+    }
 }
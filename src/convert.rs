@@ -23,9 +23,15 @@ use crate::{
     FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
     FixedU8,
 };
-use core::ops::Sub;
+use core::{
+    convert::TryFrom,
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::Sub,
+};
 #[cfg(feature = "f16")]
 use half::{bf16, f16};
+#[cfg(feature = "std")]
+use std::error::Error;
 
 macro_rules! convert {
     (
@@ -188,6 +194,205 @@ convert_lossy! { FixedU32, FixedI32, U32, LeEqU32 }
 convert_lossy! { FixedU64, FixedI64, U64, LeEqU64 }
 convert_lossy! { FixedU128, FixedI128, U128, LeEqU128 }
 
+/**
+An error which can be returned when converting a fixed-point number to
+another fixed-point type using [`TryFrom`].
+
+The conversion fails if the value overflows the destination, or if the
+value has fractional bits that the destination cannot hold.
+
+# Examples
+
+```rust
+use core::convert::TryFrom;
+use substrate_fixed::{
+    types::{I16F16, I8F8},
+    TryFromFixedError,
+};
+assert_eq!(I8F8::try_from(I16F16::from_num(-1.5)), Ok(I8F8::from_num(-1.5)));
+let error: TryFromFixedError = I8F8::try_from(I16F16::from_num(1000)).unwrap_err();
+assert!(error.is_overflow());
+let error = I8F8::try_from(I16F16::from_bits(1)).unwrap_err();
+assert!(!error.is_overflow());
+println!("Conversion error: {}", error);
+```
+
+[`TryFrom`]: https://doc.rust-lang.org/nightly/core/convert/trait.TryFrom.html
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TryFromFixedError {
+    overflow: bool,
+}
+
+impl TryFromFixedError {
+    /// Returns [`true`][`bool`] if the conversion failed because the value
+    /// overflows the destination, and [`false`][`bool`] if it failed because
+    /// fractional bits would be lost.
+    ///
+    /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+    #[inline]
+    pub fn is_overflow(&self) -> bool {
+        self.overflow
+    }
+
+    fn message(&self) -> &str {
+        if self.overflow {
+            "overflow"
+        } else {
+            "fractional bits would be lost"
+        }
+    }
+}
+
+impl Display for TryFromFixedError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(self.message(), f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for TryFromFixedError {
+    fn description(&self) -> &str {
+        self.message()
+    }
+}
+
+/**
+An error which is returned when a fixed-point number cannot be converted
+exactly to a floating-point number.
+
+The error holds the rounded floating-point value, which is what
+[`to_num`] would return.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::I32F32, Inexact};
+let f = I32F32::from_bits(0x7FFF_FFFF_0000_0001);
+let error: Inexact<f64> = f.to_f64_exact().unwrap_err();
+assert_eq!(error.rounded(), f.to_num::<f64>());
+println!("Conversion error: {}", error);
+```
+
+[`to_num`]: struct.FixedI32.html#method.to_num
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Inexact<F> {
+    rounded: F,
+}
+
+impl<F: Copy> Inexact<F> {
+    pub(crate) fn new(rounded: F) -> Inexact<F> {
+        Inexact { rounded }
+    }
+
+    /// Returns the rounded value.
+    #[inline]
+    pub fn rounded(&self) -> F {
+        self.rounded
+    }
+}
+
+impl<F> Display for Inexact<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt("value cannot be represented exactly", f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<F: core::fmt::Debug> Error for Inexact<F> {
+    fn description(&self) -> &str {
+        "value cannot be represented exactly"
+    }
+}
+
+/**
+An error which is returned when a number cannot be converted to a
+fixed-point number using [`from_num_checked_detail`].
+
+# Examples
+
+```rust
+use substrate_fixed::{types::U8F0, FromNumError};
+assert_eq!(U8F0::from_num_checked_detail(300), Err(FromNumError::Overflow));
+assert_eq!(U8F0::from_num_checked_detail(-1), Err(FromNumError::Underflow));
+println!("Conversion error: {}", FromNumError::Underflow);
+```
+
+[`from_num_checked_detail`]: struct.FixedU8.html#method.from_num_checked_detail
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FromNumError {
+    /// The value is larger than the maximum of the destination.
+    Overflow,
+    /// The value is smaller than the minimum of the destination, for
+    /// example a negative value converted to an unsigned fixed-point
+    /// number.
+    Underflow,
+}
+
+impl FromNumError {
+    fn message(&self) -> &str {
+        match self {
+            FromNumError::Overflow => "overflow",
+            FromNumError::Underflow => "underflow",
+        }
+    }
+}
+
+impl Display for FromNumError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(self.message(), f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for FromNumError {
+    fn description(&self) -> &str {
+        self.message()
+    }
+}
+
+macro_rules! try_from_narrow {
+    ($Src:ident($SrcLeEqU:ident) -> $($Dst:ident($DstLeEqU:ident)),*) => { $(
+        impl<FracSrc: $SrcLeEqU, FracDst: $DstLeEqU> TryFrom<$Src<FracSrc>> for $Dst<FracDst> {
+            type Error = TryFromFixedError;
+
+            /// Converts a fixed-point number to a narrower fixed-point
+            /// number.
+            ///
+            /// This conversion fails if the value overflows, or if it
+            /// has fractional bits that cannot be represented.
+            #[inline]
+            fn try_from(src: $Src<FracSrc>) -> Result<Self, TryFromFixedError> {
+                match Self::overflowing_from_num(src) {
+                    (_, true) => Err(TryFromFixedError { overflow: true }),
+                    (dst, false) => {
+                        if $Src::<FracSrc>::checked_from_num(dst) == Some(src) {
+                            Ok(dst)
+                        } else {
+                            Err(TryFromFixedError { overflow: false })
+                        }
+                    }
+                }
+            }
+        }
+    )* };
+}
+
+try_from_narrow! { FixedU16(LeEqU16) -> FixedU8(LeEqU8) }
+try_from_narrow! { FixedU32(LeEqU32) -> FixedU8(LeEqU8), FixedU16(LeEqU16) }
+try_from_narrow! { FixedU64(LeEqU64) -> FixedU8(LeEqU8), FixedU16(LeEqU16), FixedU32(LeEqU32) }
+try_from_narrow! {
+    FixedU128(LeEqU128) -> FixedU8(LeEqU8), FixedU16(LeEqU16), FixedU32(LeEqU32), FixedU64(LeEqU64)
+}
+try_from_narrow! { FixedI16(LeEqU16) -> FixedI8(LeEqU8) }
+try_from_narrow! { FixedI32(LeEqU32) -> FixedI8(LeEqU8), FixedI16(LeEqU16) }
+try_from_narrow! { FixedI64(LeEqU64) -> FixedI8(LeEqU8), FixedI16(LeEqU16), FixedI32(LeEqU32) }
+try_from_narrow! {
+    FixedI128(LeEqU128) -> FixedI8(LeEqU8), FixedI16(LeEqU16), FixedI32(LeEqU32), FixedI64(LeEqU64)
+}
+
 macro_rules! lossy {
     ($Src:ty) => {
         impl LossyFrom<$Src> for $Src {
@@ -1046,6 +1251,77 @@ mod tests {
         }
     }
 
+    #[test]
+    fn cross_sign_from_num() {
+        macro_rules! check {
+            ($S:ident, $U:ident) => {{
+                // negative source into unsigned
+                let neg = -$S::from_bits(1);
+                assert_eq!($U::checked_from_num(neg), None);
+                assert_eq!($U::saturating_from_num(neg), 0);
+                assert_eq!($U::checked_from_num($S::min_value()), None);
+                assert_eq!($U::saturating_from_num($S::min_value()), 0);
+                assert_eq!(
+                    $U::checked_from_num($S::from_bits(0)),
+                    Some($U::from_bits(0))
+                );
+                let s_max = $U::from_bits($U::max_value().to_bits() >> 1);
+                assert_eq!($U::checked_from_num($S::max_value()), Some(s_max));
+
+                // unsigned source with top bit set into signed
+                let top = $U::from_bits(!($U::max_value().to_bits() >> 1));
+                assert_eq!($S::checked_from_num(top), None);
+                assert_eq!($S::saturating_from_num(top), $S::max_value());
+                assert_eq!($S::checked_from_num($U::max_value()), None);
+                assert_eq!($S::saturating_from_num($U::max_value()), $S::max_value());
+                assert_eq!($S::checked_from_num(s_max), Some($S::max_value()));
+                assert_eq!(
+                    $S::checked_from_num($U::from_bits(0)),
+                    Some($S::from_bits(0))
+                );
+            }};
+        }
+        check!(I8F0, U8F0);
+        check!(I4F4, U4F4);
+        check!(I0F8, U0F8);
+        check!(I8F8, U8F8);
+        check!(I0F16, U0F16);
+        check!(I16F0, U16F0);
+        check!(I16F16, U16F16);
+        check!(I32F32, U32F32);
+        check!(I64F64, U64F64);
+        check!(I0F128, U0F128);
+        check!(I128F0, U128F0);
+
+        // different widths
+        assert_eq!(U8F8::checked_from_num(I16F16::from_num(-0.5)), None);
+        assert_eq!(U8F8::saturating_from_num(I16F16::from_num(-300)), 0);
+        assert_eq!(
+            U8F8::saturating_from_num(I16F16::from_num(300)),
+            U8F8::max_value()
+        );
+        assert_eq!(
+            U16F16::checked_from_num(I8F8::from_num(127.5)),
+            Some(U16F16::from_num(127.5))
+        );
+        assert_eq!(I8F8::checked_from_num(U16F16::from_num(128)), None);
+        assert_eq!(
+            I8F8::saturating_from_num(U16F16::from_num(128)),
+            I8F8::max_value()
+        );
+        assert_eq!(
+            I8F8::checked_from_num(U16F16::from_num(127.5)),
+            Some(I8F8::from_num(127.5))
+        );
+        assert_eq!(
+            I16F16::checked_from_num(U8F8::max_value()),
+            Some(I16F16::from_num(U8F8::max_value()))
+        );
+        // a fractional part that rounds away is still negative
+        assert_eq!(U8F0::checked_from_num(I8F8::from_num(-0.25)), None);
+        assert_eq!(U8F0::saturating_from_num(I8F8::from_num(-0.25)), 0);
+    }
+
     #[test]
     fn from_bool() {
         assert_eq!(I2F6::from(true), 1);
@@ -1054,6 +1330,57 @@ mod tests {
         assert_eq!(U1F127::from(true), 1);
     }
 
+    #[test]
+    fn bool_from_num() {
+        assert_eq!(U4F4::from_num(true), 1);
+        assert_eq!(U4F4::from_num(false), 0);
+        assert_eq!(I2F6::from_num(true), 1);
+        assert_eq!(I2F6::checked_from_num(true), Some(I2F6::from_num(1)));
+        assert_eq!(
+            I2F6::overflowing_from_num(false),
+            (I2F6::from_num(0), false)
+        );
+
+        // one does not fit in U0F8 or I1F7
+        assert_eq!(U0F8::checked_from_num(true), None);
+        assert_eq!(U0F8::checked_from_num(false), Some(U0F8::from_num(0)));
+        assert_eq!(U0F8::saturating_from_num(true), U0F8::max_value());
+        assert_eq!(U0F8::wrapping_from_num(true), 0);
+        assert_eq!(U0F8::overflowing_from_num(true), (U0F8::from_num(0), true));
+        assert_eq!(I1F7::checked_from_num(true), None);
+        assert_eq!(I1F7::saturating_from_num(true), I1F7::max_value());
+        assert_eq!(I1F7::wrapping_from_num(true), I1F7::min_value());
+        assert_eq!(I1F7::overflowing_from_num(true), (I1F7::min_value(), true));
+    }
+
+    #[test]
+    fn nonzero_from_num() {
+        use core::num::{NonZeroI16, NonZeroI8, NonZeroU128, NonZeroU32, NonZeroU8};
+
+        assert_eq!(U16F16::from_num(NonZeroU8::new(3).unwrap()), 3);
+        assert_eq!(I16F16::from_num(NonZeroI8::new(-3).unwrap()), -3);
+        assert_eq!(
+            U16F16::checked_from_num(NonZeroU32::new(70_000).unwrap()),
+            None
+        );
+        assert_eq!(
+            U16F16::saturating_from_num(NonZeroU32::new(70_000).unwrap()),
+            U16F16::max_value()
+        );
+        assert_eq!(
+            U16F16::wrapping_from_num(NonZeroU32::new(70_000).unwrap()),
+            70_000 - 65_536
+        );
+        assert_eq!(
+            U8F8::overflowing_from_num(NonZeroI16::new(-1).unwrap()),
+            (U8F8::from_num(255), true)
+        );
+        assert_eq!(
+            I64F64::checked_from_num(NonZeroU128::new(1 << 62).unwrap()),
+            Some(I64F64::from_num(1u64 << 62))
+        );
+    }
+
     #[test]
     fn to_size() {
         let min_i24 = I24F8::min_value();
@@ -1530,4 +1857,119 @@ mod tests {
         );
         assert_eq!(bf16::lossy_from((-133f32).exp2() * 0.5), bf16::from_bits(0));
     }
+
+    #[test]
+    fn try_from_narrow() {
+        use crate::{
+            types::{I0F8, I16F16, I32F32, I8F8, U0F64, U16F16, U32F32, U4F4, U8F8},
+            TryFromFixedError,
+        };
+        use core::convert::TryFrom;
+
+        // lossless widening round-trips through From and TryFrom
+        for &f in &[0.0, 1.5, -2.25, 127.99609375, -128.0] {
+            let narrow = I8F8::from_num(f);
+            let wide = I16F16::from(narrow);
+            assert_eq!(I8F8::try_from(wide), Ok(narrow));
+            let wide = I32F32::from(narrow);
+            assert_eq!(I8F8::try_from(wide), Ok(narrow));
+        }
+        let narrow = U4F4::from_num(15.9375);
+        assert_eq!(U4F4::try_from(U32F32::from(narrow)), Ok(narrow));
+        assert_eq!(
+            U8F8::try_from(U16F16::from_num(255.5)),
+            Ok(U8F8::from_num(255.5))
+        );
+
+        let overflow = TryFromFixedError { overflow: true };
+        let inexact = TryFromFixedError { overflow: false };
+        assert_eq!(I8F8::try_from(I16F16::from_num(128)).unwrap_err(), overflow);
+        assert_eq!(
+            I8F8::try_from(I16F16::from_num(-129)).unwrap_err(),
+            overflow
+        );
+        assert_eq!(
+            I8F8::try_from(I16F16::from_num(1.25) + I16F16::DELTA).unwrap_err(),
+            inexact
+        );
+        assert_eq!(I0F8::try_from(I32F32::from_num(0.5)).unwrap_err(), overflow);
+        assert_eq!(
+            I0F8::try_from(I32F32::from_num(-0.5)),
+            Ok(I0F8::from_num(-0.5))
+        );
+        assert_eq!(
+            U8F8::try_from(U0F64::from_num(0.5)),
+            Ok(U8F8::from_num(0.5))
+        );
+        assert_eq!(U8F8::try_from(U0F64::max_value()).unwrap_err(), inexact);
+        assert_eq!(U8F8::try_from(U16F16::from_num(256)).unwrap_err(), overflow);
+    }
+
+    #[test]
+    fn non_finite_from_num() {
+        use crate::types::{I8F8, U8F8};
+        for &nan in &[f32::NAN, -f32::NAN] {
+            assert_eq!(I8F8::checked_from_num(nan), None);
+            assert_eq!(U8F8::checked_from_num(f64::from(nan)), None);
+        }
+        assert_eq!(I8F8::checked_from_num(f32::INFINITY), None);
+        assert_eq!(I8F8::checked_from_num(f64::NEG_INFINITY), None);
+        assert_eq!(I8F8::saturating_from_num(f32::INFINITY), I8F8::max_value());
+        assert_eq!(
+            I8F8::saturating_from_num(f64::NEG_INFINITY),
+            I8F8::min_value()
+        );
+        assert_eq!(
+            U8F8::saturating_from_num(f64::NEG_INFINITY),
+            U8F8::min_value()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "NaN")]
+    fn saturating_from_nan() {
+        let _ = crate::types::I8F8::saturating_from_num(f32::NAN);
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    fn from_num_f16() {
+        use half::f16;
+        let val = f16::from_f32(-2.5);
+        assert_eq!(I16F16::from_num(val), -2.5);
+        assert_eq!(I16F16::checked_from_num(val), Some(I16F16::from_num(-2.5)));
+        assert_eq!(I16F16::saturating_from_num(val), -2.5);
+        assert_eq!(
+            U16F16::checked_from_num(f16::MAX),
+            Some(U16F16::from_num(65504))
+        );
+        assert_eq!(I8F8::checked_from_num(f16::MAX), None);
+        assert_eq!(I8F8::saturating_from_num(f16::MAX), I8F8::max_value());
+
+        assert_eq!(I16F16::checked_from_num(f16::NAN), None);
+        assert_eq!(I16F16::checked_from_num(f16::INFINITY), None);
+        assert_eq!(I16F16::checked_from_num(f16::NEG_INFINITY), None);
+        assert_eq!(
+            I16F16::saturating_from_num(f16::INFINITY),
+            I16F16::max_value()
+        );
+        assert_eq!(
+            I16F16::saturating_from_num(f16::NEG_INFINITY),
+            I16F16::min_value()
+        );
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    #[should_panic]
+    fn from_num_f16_nan() {
+        let _ = I16F16::from_num(half::f16::NAN);
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    #[should_panic]
+    fn from_num_f16_infinity() {
+        let _ = I16F16::from_num(half::f16::INFINITY);
+    }
 }
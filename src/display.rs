@@ -22,8 +22,8 @@ use crate::{
 use core::{
     cmp::{self, Ordering},
     fmt::{
-        Alignment, Binary, Debug, Display, Formatter, LowerHex, Octal, Result as FmtResult,
-        UpperHex,
+        Alignment, Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal,
+        Result as FmtResult, UpperExp, UpperHex,
     },
     mem, str,
 };
@@ -402,17 +402,165 @@ fn fmt_radix2<U: FmtHelper>(
     buf.finish(radix, neg, frac_rem_cmp_msb, fmt)
 }
 
+fn fmt_exp<U: FmtHelper>(
+    (neg, abs): (bool, U),
+    frac_nbits: u32,
+    upper: bool,
+    fmt: &mut Formatter,
+) -> FmtResult {
+    let (int, frac) = if frac_nbits == 0 {
+        (abs, U::ZERO)
+    } else if frac_nbits == U::NBITS {
+        (U::ZERO, abs)
+    } else {
+        (abs >> frac_nbits, abs << (U::NBITS - frac_nbits))
+    };
+    let int_used_nbits = U::NBITS - int.leading_zeros();
+    let int_digits = ceil_log10_2_times(int_used_nbits);
+    let frac_used_nbits = U::NBITS - frac.trailing_zeros();
+    // Without a precision, we use the same digits as Display. With a
+    // precision, we write all the digits and round them below, as the
+    // precision counts significant digits and not fractional digits.
+    let auto_prec = fmt.precision().is_none();
+    let frac_digits = if auto_prec {
+        ceil_log10_2_times(frac_nbits)
+    } else {
+        frac_used_nbits
+    };
+
+    let mut buf = Buffer::new();
+    buf.set_len(int_digits, frac_digits);
+    int.write_int_dec(int_used_nbits, &mut buf);
+    let frac_rem_cmp_msb = frac.write_frac_dec(frac_nbits, auto_prec, &mut buf);
+    if auto_prec {
+        buf.round_and_trim(Radix::Dec.max(), frac_rem_cmp_msb);
+    }
+
+    // digits[0] is the leading zero of the buffer, and the digit at
+    // index i has a weight of 10^(int_digits - i).
+    let mut digits = [0u8; 130];
+    let int_end = buf.int_digits + 1;
+    let len = int_end + buf.frac_digits;
+    digits[..int_end].copy_from_slice(&buf.data[..int_end]);
+    digits[int_end..len].copy_from_slice(&buf.data[int_end + 1..len + 1]);
+    let (mut lead, mut end) = match digits[..len].iter().position(|&d| d != 0) {
+        Some(lead) => {
+            let trailing_zeros = digits[..len].iter().rev().position(|&d| d != 0);
+            (lead, len - trailing_zeros.unwrap())
+        }
+        None => (buf.int_digits, int_end),
+    };
+    if let Some(precision) = fmt.precision() {
+        if end - lead - 1 > precision {
+            let cut = lead + 1 + precision;
+            let round_up = match digits[cut].cmp(&5) {
+                Ordering::Less => false,
+                Ordering::Greater => true,
+                Ordering::Equal => end > cut + 1 || digits[cut - 1].is_odd(),
+            };
+            if round_up {
+                // digits[0] is zero when there is a precision, so this
+                // carry stops at index 0 at the latest
+                let mut i = cut - 1;
+                while digits[i] == 9 {
+                    digits[i] = 0;
+                    i -= 1;
+                }
+                digits[i] += 1;
+                lead = cmp::min(lead, i);
+            }
+            end = lead + 1 + precision;
+        }
+    }
+    let exp = buf.int_digits as i32 - lead as i32;
+
+    let sign = if neg {
+        "-"
+    } else if fmt.sign_plus() {
+        "+"
+    } else {
+        ""
+    };
+    let mantissa_frac = end - lead - 1;
+    let end_zeros = fmt
+        .precision()
+        .map(|x| x.saturating_sub(mantissa_frac))
+        .unwrap_or(0);
+    let point_len = if mantissa_frac + end_zeros > 0 { 1 } else { 0 };
+    let mut exp_buf = [0u8; 4];
+    let mut exp_begin = exp_buf.len();
+    let mut exp_abs = exp.abs();
+    loop {
+        exp_begin -= 1;
+        exp_buf[exp_begin] = b'0' + (exp_abs % 10) as u8;
+        exp_abs /= 10;
+        if exp_abs == 0 {
+            break;
+        }
+    }
+    if exp < 0 {
+        exp_begin -= 1;
+        exp_buf[exp_begin] = b'-';
+    }
+    let exp_str = str::from_utf8(&exp_buf[exp_begin..]).unwrap();
+
+    let req_width = sign.len() + 1 + point_len + mantissa_frac + end_zeros + 1 + exp_str.len();
+    let pad = fmt
+        .width()
+        .and_then(|w| w.checked_sub(req_width))
+        .unwrap_or(0);
+    let (pad_left, pad_zeros, pad_right) = if fmt.sign_aware_zero_pad() {
+        (0, pad, 0)
+    } else {
+        match fmt.align() {
+            Some(Alignment::Left) => (0, 0, pad),
+            Some(Alignment::Center) => (pad / 2, 0, pad - pad / 2),
+            None | Some(Alignment::Right) => (pad, 0, 0),
+        }
+    };
+    let fill = fmt.fill();
+
+    use core::fmt::Write;
+    for _ in 0..pad_left {
+        fmt.write_char(fill)?;
+    }
+    fmt.write_str(sign)?;
+    for _ in 0..pad_zeros {
+        fmt.write_char('0')?;
+    }
+    fmt.write_char(char::from(b'0' + digits[lead]))?;
+    if point_len > 0 {
+        fmt.write_char('.')?;
+    }
+    for &d in &digits[lead + 1..end] {
+        fmt.write_char(char::from(b'0' + d))?;
+    }
+    for _ in 0..end_zeros {
+        fmt.write_char('0')?;
+    }
+    fmt.write_char(if upper { 'E' } else { 'e' })?;
+    fmt.write_str(exp_str)?;
+    for _ in 0..pad_right {
+        fmt.write_char(fill)?;
+    }
+    Ok(())
+}
+
 macro_rules! impl_fmt {
-    ($Fixed:ident($LeEqU:ident)) => {
+    ($Fixed:ident($LeEqU:ident, $prefix:expr)) => {
         impl<Frac: $LeEqU> Display for $Fixed<Frac> {
             fn fmt(&self, f: &mut Formatter) -> FmtResult {
                 fmt_dec(self.to_bits().neg_abs(), Self::FRAC_NBITS, f)
             }
         }
 
+        /// Formats the value in decimal after the type, for example
+        /// `I16F16(1.5)`. Width, fill and precision apply to the value.
         impl<Frac: $LeEqU> Debug for $Fixed<Frac> {
             fn fmt(&self, f: &mut Formatter) -> FmtResult {
-                fmt_dec(self.to_bits().neg_abs(), Self::FRAC_NBITS, f)
+                write!(f, "{}{}F{}(", $prefix, Self::INT_NBITS, Self::FRAC_NBITS)?;
+                fmt_dec(self.to_bits().neg_abs(), Self::FRAC_NBITS, f)?;
+                f.write_str(")")
             }
         }
 
@@ -439,19 +587,31 @@ macro_rules! impl_fmt {
                 fmt_radix2(self.to_bits().neg_abs(), Self::FRAC_NBITS, Radix::UpHex, f)
             }
         }
+
+        impl<Frac: $LeEqU> LowerExp for $Fixed<Frac> {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                fmt_exp(self.to_bits().neg_abs(), Self::FRAC_NBITS, false, f)
+            }
+        }
+
+        impl<Frac: $LeEqU> UpperExp for $Fixed<Frac> {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                fmt_exp(self.to_bits().neg_abs(), Self::FRAC_NBITS, true, f)
+            }
+        }
     };
 }
 
-impl_fmt! { FixedU8(LeEqU8) }
-impl_fmt! { FixedU16(LeEqU16) }
-impl_fmt! { FixedU32(LeEqU32) }
-impl_fmt! { FixedU64(LeEqU64) }
-impl_fmt! { FixedU128(LeEqU128) }
-impl_fmt! { FixedI8(LeEqU8) }
-impl_fmt! { FixedI16(LeEqU16) }
-impl_fmt! { FixedI32(LeEqU32) }
-impl_fmt! { FixedI64(LeEqU64) }
-impl_fmt! { FixedI128(LeEqU128) }
+impl_fmt! { FixedU8(LeEqU8, "U") }
+impl_fmt! { FixedU16(LeEqU16, "U") }
+impl_fmt! { FixedU32(LeEqU32, "U") }
+impl_fmt! { FixedU64(LeEqU64, "U") }
+impl_fmt! { FixedU128(LeEqU128, "U") }
+impl_fmt! { FixedI8(LeEqU8, "I") }
+impl_fmt! { FixedI16(LeEqU16, "I") }
+impl_fmt! { FixedI32(LeEqU32, "I") }
+impl_fmt! { FixedI64(LeEqU64, "I") }
+impl_fmt! { FixedI128(LeEqU128, "I") }
 
 // ceil(i × log_10 2), works for input < 112_816
 fn ceil_log10_2_times(int_bits: u32) -> u32 {
@@ -523,6 +683,87 @@ mod tests {
         assert_eq!(format!("{:#^08}", pos), "000012.3");
     }
 
+    #[test]
+    fn precision_and_width() {
+        let f = I16F16::from_num(1.5);
+        assert_eq!(format!("{:.3}", f), "1.500");
+        assert_eq!(format!("{:.0}", f), "2");
+        assert_eq!(format!("{:.0}", I16F16::from_num(2.5)), "2");
+        assert_eq!(format!("{:.0}", I16F16::from_num(-0.5)), "-0");
+        assert_eq!(format!("{:.10}", f), "1.5000000000");
+        // I8F8::DELTA has eight exact decimal digits
+        assert_eq!(format!("{:.10}", I8F8::from_bits(1)), "0.0039062500");
+        assert_eq!(format!("{:.2}", I8F8::from_bits(1)), "0.00");
+        assert_eq!(format!("{:.1}", I16F16::from_num(2.25)), "2.2");
+        assert_eq!(format!("{:.1}", I16F16::from_num(2.75)), "2.8");
+
+        assert_eq!(format!("{:>8.2}", f), "    1.50");
+        assert_eq!(format!("{:8.2}", f), "    1.50");
+        assert_eq!(format!("{:*<8.2}", -f), "-1.50***");
+        assert_eq!(format!("{:*^9.1}", I16F16::from_num(2.25)), "***2.2***");
+        assert_eq!(format!("{:08.2}", -f), "-0001.50");
+        assert_eq!(format!("{:3.2}", f), "1.50");
+    }
+
+    #[test]
+    fn exp() {
+        assert_eq!(format!("{:e}", I16F16::from_num(1500)), "1.5e3");
+        assert_eq!("1.5e3".parse::<I16F16>(), Ok(I16F16::from_num(1500)));
+        assert_eq!(format!("{:E}", I16F16::from_num(1500)), "1.5E3");
+        assert_eq!(format!("{:e}", I16F16::from_num(-0.125)), "-1.25e-1");
+        assert_eq!(format!("{:e}", U0F32::from_num(0.000_001)), "1e-6");
+        assert_eq!(format!("{:e}", I16F16::from_num(0)), "0e0");
+        assert_eq!(format!("{:e}", U8F8::from_num(1)), "1e0");
+        assert_eq!(format!("{:e}", U0F128::from_bits(1)), "3e-39");
+        assert_eq!(
+            format!("{:e}", U128F0::max_value()),
+            format!("{:e}", u128::MAX)
+        );
+
+        assert_eq!(format!("{:.2e}", I16F16::from_num(1500)), "1.50e3");
+        assert_eq!(format!("{:.0e}", I16F16::from_num(1500)), "2e3");
+        assert_eq!(format!("{:.0e}", I16F16::from_num(2500)), "2e3");
+        assert_eq!(format!("{:.1e}", I16F16::from_num(9.96)), "1.0e1");
+        assert_eq!(format!("{:.3e}", I16F16::from_num(0)), "0.000e0");
+        assert_eq!(format!("{:+.1E}", U8F8::from_num(0.5)), "+5.0E-1");
+        assert_eq!(format!("{:>8.1e}", I16F16::from_num(-1500)), "  -1.5e3");
+        assert_eq!(format!("{:*<8e}", I16F16::from_num(1500)), "1.5e3***");
+        assert_eq!(format!("{:08.1e}", I16F16::from_num(-1500)), "-001.5e3");
+
+        // with a precision, the exact value is rounded like f64 does
+        for i in 0..(1 << 12) {
+            let f = I16F16::from_bits((i * 0x0001_2345) ^ -0x0765_4321);
+            let flo = f.to_num::<f64>();
+            for &prec in &[0, 1, 3, 8, 20] {
+                assert_eq!(format!("{:.*e}", prec, f), format!("{:.*e}", prec, flo));
+            }
+            // without a precision, the digits are those of Display
+            let s = format!("{:e}", f);
+            assert_eq!(
+                s.parse::<f64>().unwrap(),
+                f.to_string().parse::<f64>().unwrap()
+            );
+            assert_eq!(s.parse::<I16F16>(), Ok(f));
+        }
+        for i in 0..(1 << 10) {
+            // 53 significant bits, so that the value is exact in f64
+            let bits = ((i * 0x1234_5678_9ABC_u64) & ((1 << 53) - 1)) | (1 << 52);
+            for &shift in &[0, 40, 74] {
+                let f = I64F64::from_bits(i128::from(bits) << shift);
+                let u = U0F128::from_bits(u128::from(bits) << shift);
+                let (flo, uflo) = (f.to_num::<f64>(), u.to_num::<f64>());
+                assert_eq!(format!("{:e}", f).parse::<I64F64>(), Ok(f));
+                assert_eq!(format!("{:E}", -f).parse::<I64F64>(), Ok(-f));
+                assert_eq!(format!("{:e}", u).parse::<U0F128>(), Ok(u));
+                for &prec in &[0, 5, 15, 40] {
+                    assert_eq!(format!("{:.*e}", prec, f), format!("{:.*e}", prec, flo));
+                    assert_eq!(format!("{:.*e}", prec, -f), format!("{:.*e}", prec, -flo));
+                    assert_eq!(format!("{:.*e}", prec, u), format!("{:.*e}", prec, uflo));
+                }
+            }
+        }
+    }
+
     fn trim_frac_zeros(mut x: &str) -> &str {
         while x.ends_with('0') {
             x = &x[..x.len() - 1];
@@ -570,6 +811,68 @@ mod tests {
         }
     }
 
+    #[test]
+    fn binary() {
+        assert_eq!(format!("{:b}", I8F8::from_num(2.5)), "10.1");
+        assert_eq!(format!("{:.8b}", I8F8::from_num(2.5)), "10.10000000");
+        assert_eq!(format!("{:#b}", I8F8::from_num(2.5)), "0b10.1");
+        // negative values are printed as a sign and a magnitude
+        assert_eq!(format!("{:b}", I8F8::from_num(-2.5)), "-10.1");
+        assert_eq!(format!("{:b}", I8F8::min_value()), "-10000000");
+        for i in 0..(1u32 << 7) {
+            let p = 0x1234_5678_9abc_def0u64 ^ u64::from(i);
+            let n = -0x1234_5678_9abc_def0i64 ^ i64::from(i);
+            let f_p = U57F7::from_bits(p);
+            let f_n = I57F7::from_bits(n);
+            let mut check_p = format!("{:b}.{:07b}", p >> 7, p & 0x7f);
+            up_frac_digits(&mut check_p, 1000);
+            let trimmed_p = trim_frac_zeros(&check_p);
+            let mut check_n = format!("-{:b}.{:07b}", n.abs() >> 7, n.abs() & 0x7f);
+            up_frac_digits(&mut check_n, 1000);
+            let trimmed_n = trim_frac_zeros(&check_n);
+            assert_eq!(format!("{:.1000b}", f_p), check_p);
+            assert_eq!(format!("{:b}", f_p), trimmed_p);
+            assert_eq!(format!("{:.1000b}", f_n), check_n);
+            assert_eq!(format!("{:b}", f_n), trimmed_n);
+        }
+    }
+
+    #[test]
+    fn hex_round_trip() {
+        assert_eq!(format!("{:X}", U8F8::from_num(255.5)), "FF.8");
+        assert_eq!(format!("{:x}", U8F8::from_num(255.5)), "ff.8");
+        for i in 0..(1u32 << 7) {
+            let p = 0x1234_5678_9abc_def0u64 ^ u64::from(i);
+            let n = -0x1234_5678_9abc_def0i64 ^ i64::from(i);
+            let f_p = U57F7::from_bits(p);
+            let f_n = I57F7::from_bits(n);
+            assert_eq!(U57F7::from_str_radix(&format!("{:x}", f_p), 16), Ok(f_p));
+            assert_eq!(U57F7::from_str_radix(&format!("{:X}", f_p), 16), Ok(f_p));
+            assert_eq!(I57F7::from_str_radix(&format!("{:x}", f_n), 16), Ok(f_n));
+            assert_eq!(I57F7::from_str_radix(&format!("{:X}", f_n), 16), Ok(f_n));
+        }
+        for &f in &[I0F8::min_value(), I0F8::max_value(), I0F8::from_bits(-1)] {
+            assert_eq!(I0F8::from_str_radix(&format!("{:x}", f), 16), Ok(f));
+        }
+        for &f in &[U0F128::max_value(), U0F128::from_bits(1)] {
+            assert_eq!(U0F128::from_str_radix(&format!("{:x}", f), 16), Ok(f));
+        }
+    }
+
+    #[test]
+    fn octal_round_trip() {
+        assert_eq!(format!("{:o}", I8F8::max_value()), "177.776");
+        assert_eq!(format!("{:o}", I8F8::from_num(-0.5)), "-0.4");
+        for i in 0..=0xffff_u16 {
+            let u0 = U0F16::from_bits(i);
+            assert_eq!(U0F16::from_str_radix(&format!("{:o}", u0), 8), Ok(u0));
+            let i8 = I8F8::from_bits(i as i16);
+            assert_eq!(I8F8::from_str_radix(&format!("{:o}", i8), 8), Ok(i8));
+            let u16 = U16F0::from_bits(i);
+            assert_eq!(U16F0::from_str_radix(&format!("{:o}", u16), 8), Ok(u16));
+        }
+    }
+
     #[test]
     fn dec() {
         for i in 0..(1 << 7) {
@@ -822,4 +1125,17 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", I16F16::from_num(1.5)), "I16F16(1.5)");
+        assert_eq!(format!("{:?}", I8F8::from_num(-3.25)), "I8F8(-3.25)");
+        assert_eq!(format!("{:?}", U0F8::from_num(0.5)), "U0F8(0.5)");
+        assert_eq!(
+            format!("{:?}", U128F0::max_value()),
+            format!("U128F0({})", u128::MAX)
+        );
+        assert_eq!(format!("{:.1?}", I16F16::from_num(1.25)), "I16F16(1.2)");
+        assert_eq!(format!("{:>5?}", U8F8::from_num(2)), "U8F8(    2)");
+    }
 }
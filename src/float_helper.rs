@@ -13,7 +13,7 @@
 // <https://www.apache.org/licenses/LICENSE-2.0> and
 // <https://opensource.org/licenses/MIT>.
 
-use crate::helpers::{FloatKind, IntHelper, ToFixedHelper, ToFloatHelper, Widest};
+use crate::helpers::{FloatKind, IntHelper, Round, ToFixedHelper, ToFloatHelper, Widest};
 use core::cmp::Ordering;
 #[cfg(feature = "f16")]
 use half::{bf16, f16};
@@ -36,7 +36,10 @@ pub trait FloatHelper: Copy {
     fn parts(self) -> (bool, i32, Self::Bits);
 
     fn from_to_float_helper(val: ToFloatHelper, frac_bits: u32, int_bits: u32) -> Self;
-    fn to_float_kind(self, dst_frac_bits: u32, dst_int_bits: u32) -> FloatKind;
+    fn to_float_kind(self, dst_frac_bits: u32, dst_int_bits: u32) -> FloatKind {
+        self.to_float_kind_round(dst_frac_bits, dst_int_bits, Round::Nearest)
+    }
+    fn to_float_kind_round(self, dst_frac_bits: u32, dst_int_bits: u32, round: Round) -> FloatKind;
 }
 
 macro_rules! sealed_float {
@@ -135,11 +138,16 @@ macro_rules! sealed_float {
             }
 
             #[inline]
-            fn to_float_kind(self, dst_frac_bits: u32, dst_int_bits: u32) -> FloatKind {
+            fn to_float_kind_round(
+                self,
+                dst_frac_bits: u32,
+                dst_int_bits: u32,
+                round: Round,
+            ) -> FloatKind {
                 let prec = Self::PREC as i32;
 
                 let (neg, exp, mut mantissa) = self.parts();
-                if exp == Self::EXP_MAX {
+                if exp > Self::EXP_MAX {
                     if mantissa == 0 {
                         return FloatKind::Infinite { neg };
                     } else {
@@ -161,7 +169,24 @@ macro_rules! sealed_float {
 
                 let mut src_frac_bits = prec - 1 - exp;
                 let need_to_shr = src_frac_bits - dst_frac_bits as i32;
+                // directed rounding of the absolute value
+                let away_from_zero = match round {
+                    Round::Nearest | Round::Trunc => false,
+                    Round::Floor => neg,
+                    Round::Ceil => !neg,
+                };
                 if need_to_shr > prec {
+                    if round != Round::Nearest && away_from_zero {
+                        let one: $IBits = if neg { -1 } else { 1 };
+                        let mut conv =
+                            one.to_fixed_helper(dst_frac_bits as i32, dst_frac_bits, dst_int_bits);
+                        conv.dir = if neg {
+                            Ordering::Less
+                        } else {
+                            Ordering::Greater
+                        };
+                        return FloatKind::Finite { neg, conv };
+                    }
                     let dir = if neg {
                         Ordering::Greater
                     } else {
@@ -181,6 +206,13 @@ macro_rules! sealed_float {
                     let tie = will_be_lsb >> 1;
                     if removed_bits == 0 {
                         // removed nothing
+                    } else if round != Round::Nearest {
+                        if away_from_zero {
+                            mantissa += will_be_lsb;
+                            dir = Ordering::Greater;
+                        } else {
+                            dir = Ordering::Less;
+                        }
                     } else if removed_bits < tie {
                         dir = Ordering::Less;
                     } else if removed_bits > tie || mantissa & will_be_lsb != 0 {
@@ -23,9 +23,10 @@ use crate::{
 };
 use core::{
     cmp::Ordering,
+    convert::TryFrom,
     fmt::{Display, Formatter, Result as FmtResult},
     ops::{Add, Mul, Shl, Shr},
-    str::FromStr,
+    str::{self, FromStr},
 };
 #[cfg(feature = "std")]
 use std::error::Error;
@@ -524,28 +525,106 @@ let error: ParseFixedError = match s.parse::<I16F16>() {
 };
 println!("Parse error: {}", error);
 ```
+
+The fields of the error are private, so more information can be added
+to it without breaking existing code. Errors can be compared and
+hashed, for example to deduplicate them in a [`HashSet`].
+
+[`HashSet`]: https://doc.rust-lang.org/nightly/std/collections/struct.HashSet.html
 */
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct ParseFixedError {
     kind: ParseErrorKind,
+    position: Option<usize>,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum ParseErrorKind {
+/**
+The kind of error that can be returned when parsing a fixed-point number.
+
+This is returned by [`ParseFixedError::kind`].
+
+# Examples
+
+```rust
+use substrate_fixed::{types::I16F16, ParseErrorKind};
+let kind = |s: &str| s.parse::<I16F16>().unwrap_err().kind();
+assert_eq!(kind("1.2.3"), ParseErrorKind::TooManyPoints);
+assert_eq!(kind("1x"), ParseErrorKind::InvalidDigit);
+assert_eq!(kind("."), ParseErrorKind::NoDigits);
+assert_eq!(kind("65536"), ParseErrorKind::Overflow);
+```
+
+Adding a variant to this enum is considered a breaking change, so
+matching on all the variants without a wildcard arm is supported.
+
+[`ParseFixedError::kind`]: struct.ParseFixedError.html#method.kind
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParseErrorKind {
+    /// The string contains an invalid digit or an unexpected sign.
     InvalidDigit,
+    /// The string has no digits.
     NoDigits,
+    /// The string contains more than one point.
     TooManyPoints,
+    /// The parsed value does not fit in the destination type.
     Overflow,
 }
 
 impl From<ParseErrorKind> for ParseFixedError {
     #[inline]
     fn from(kind: ParseErrorKind) -> ParseFixedError {
-        ParseFixedError { kind }
+        ParseFixedError {
+            kind,
+            position: None,
+        }
     }
 }
 
 impl ParseFixedError {
+    /// Returns the kind of error that occurred while parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::U8F8, ParseErrorKind};
+    /// let err = "256".parse::<U8F8>().unwrap_err();
+    /// assert_eq!(err.kind(), ParseErrorKind::Overflow);
+    /// ```
+    #[inline]
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte offset in the string of the invalid digit or of the
+    /// extra point, if the error is of kind
+    /// [`InvalidDigit`][`ParseErrorKind::InvalidDigit`] or
+    /// [`TooManyPoints`][`ParseErrorKind::TooManyPoints`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::types::I16F16;
+    /// let err = "12.3x4".parse::<I16F16>().unwrap_err();
+    /// assert_eq!(err.position(), Some(4));
+    /// let err = "".parse::<I16F16>().unwrap_err();
+    /// assert_eq!(err.position(), None);
+    /// ```
+    ///
+    /// [`ParseErrorKind::InvalidDigit`]: enum.ParseErrorKind.html#variant.InvalidDigit
+    /// [`ParseErrorKind::TooManyPoints`]: enum.ParseErrorKind.html#variant.TooManyPoints
+    #[inline]
+    pub fn position(&self) -> Option<usize> {
+        self.position
+    }
+
+    fn at(kind: ParseErrorKind, position: usize) -> ParseFixedError {
+        ParseFixedError {
+            kind,
+            position: Some(position),
+        }
+    }
+
     fn message(&self) -> &str {
         use self::ParseErrorKind::*;
         match self.kind {
@@ -582,21 +661,21 @@ fn parse_bounds(bytes: &[u8], radix: u32) -> Result<Parse<'_>, ParseFixedError>
         match (byte, radix) {
             (b'+', _) => {
                 if sign.is_some() || point.is_some() || has_any_digit {
-                    return Err(ParseErrorKind::InvalidDigit.into());
+                    return Err(ParseFixedError::at(ParseErrorKind::InvalidDigit, index));
                 }
                 sign = Some(false);
                 continue;
             }
             (b'-', _) => {
                 if sign.is_some() || point.is_some() || has_any_digit {
-                    return Err(ParseErrorKind::InvalidDigit.into());
+                    return Err(ParseFixedError::at(ParseErrorKind::InvalidDigit, index));
                 }
                 sign = Some(true);
                 continue;
             }
             (b'.', _) => {
                 if point.is_some() {
-                    return Err(ParseErrorKind::TooManyPoints.into());
+                    return Err(ParseFixedError::at(ParseErrorKind::TooManyPoints, index));
                 }
                 point = Some(index);
                 trimmed_frac_end = Some(index + 1);
@@ -616,7 +695,7 @@ fn parse_bounds(bytes: &[u8], radix: u32) -> Result<Parse<'_>, ParseFixedError>
                 }
                 has_any_digit = true;
             }
-            _ => return Err(ParseErrorKind::InvalidDigit.into()),
+            _ => return Err(ParseFixedError::at(ParseErrorKind::InvalidDigit, index)),
         }
     }
     if !has_any_digit {
@@ -635,6 +714,104 @@ fn parse_bounds(bytes: &[u8], radix: u32) -> Result<Parse<'_>, ParseFixedError>
     Ok(Parse { neg, int, frac })
 }
 
+// Enough for the 128 least significant integer digits, which decide the
+// wrapped value, plus one more so that dropping digits is flagged as an
+// overflow by the integer parsers.
+const EXP_MAX_INT_LEN: usize = 129;
+// Ties need at most 129 fractional digits for 128 fractional bits; the
+// digits after those only matter if they are non-zero.
+const EXP_MAX_FRAC_LEN: usize = 130;
+const EXP_BUF_LEN: usize = EXP_MAX_INT_LEN + EXP_MAX_FRAC_LEN + 1;
+
+// Like parse_bounds, but for radix 10 the number can be followed by an
+// exponent such as e3 or E-3. The point is moved by the exponent into
+// buf, so that the result is rounded only once.
+fn parse_bounds_exp<'a>(
+    bytes: &'a [u8],
+    radix: u32,
+    buf: &'a mut [u8; EXP_BUF_LEN],
+) -> Result<Parse<'a>, ParseFixedError> {
+    let e_index = match bytes.iter().position(|&b| b == b'e' || b == b'E') {
+        Some(e_index) if radix == 10 => e_index,
+        _ => return parse_bounds(bytes, radix),
+    };
+    let Parse { neg, int, frac } = parse_bounds(&bytes[..e_index], radix)?;
+
+    let exp_bytes = &bytes[e_index + 1..];
+    let (exp_neg, exp_start) = match exp_bytes.first() {
+        Some(b'+') => (false, 1),
+        Some(b'-') => (true, 1),
+        _ => (false, 0),
+    };
+    if exp_start == exp_bytes.len() {
+        return Err(ParseFixedError::at(ParseErrorKind::InvalidDigit, e_index));
+    }
+    let mut exp = 0i64;
+    for (index, &byte) in exp_bytes.iter().enumerate().skip(exp_start) {
+        if !byte.is_ascii_digit() {
+            let position = e_index + 1 + index;
+            return Err(ParseFixedError::at(ParseErrorKind::InvalidDigit, position));
+        }
+        exp = exp
+            .saturating_mul(10)
+            .saturating_add(i64::from(byte - b'0'));
+    }
+    if exp_neg {
+        exp = -exp;
+    }
+
+    // The digits are int followed by frac, and the point is moved from
+    // after int by exp places.
+    let len = int.len() + frac.len();
+    let digit = |i: i64| {
+        if i < 0 || i >= len as i64 {
+            b'0'
+        } else if (i as usize) < int.len() {
+            int[i as usize]
+        } else {
+            frac[i as usize - int.len()]
+        }
+    };
+    let (first, last) = match (0..len).position(|i| digit(i as i64) != b'0') {
+        Some(first) => {
+            let last = (0..len).rposition(|i| digit(i as i64) != b'0');
+            (first as i64, last.unwrap() as i64)
+        }
+        None => {
+            return Ok(Parse {
+                neg,
+                int: &[],
+                frac: &[],
+            })
+        }
+    };
+    let point = (int.len() as i64).saturating_add(exp);
+
+    let int_start = first.max(point.saturating_sub(EXP_MAX_INT_LEN as i64));
+    let int_len = point.saturating_sub(int_start).max(0) as usize;
+    for (i, b) in buf[..int_len].iter_mut().enumerate() {
+        *b = digit(int_start + i as i64);
+    }
+    let frac_len = (last + 1).saturating_sub(point).max(0);
+    let (frac_len, sticky) = if frac_len > EXP_MAX_FRAC_LEN as i64 {
+        (EXP_MAX_FRAC_LEN, true)
+    } else {
+        (frac_len as usize, false)
+    };
+    let frac_buf = &mut buf[int_len..];
+    for (i, b) in frac_buf[..frac_len].iter_mut().enumerate() {
+        *b = digit(point + i as i64);
+    }
+    let frac_len = if sticky {
+        frac_buf[frac_len] = b'1';
+        frac_len + 1
+    } else {
+        frac_len
+    };
+    let (int, frac) = buf[..int_len + frac_len].split_at(int_len);
+    Ok(Parse { neg, int, frac })
+}
+
 fn frac_is_half(bytes: &[u8], radix: u32) -> bool {
     // since zeros are trimmed, there must be exatly one byte
     bytes.len() == 1 && bytes[0] - b'0' == (radix as u8) / 2
@@ -648,18 +825,140 @@ pub(crate) trait FromStrRadix: Sized {
     fn overflowing_from_str_radix(s: &str, radix: u32) -> Result<(Self, bool), Self::Err>;
 }
 
+// Returns the length of the longest prefix of bytes that has the form
+// [sign] digits [. digits], and whether that prefix has any digits.
+fn numeric_prefix(bytes: &[u8], radix: u32) -> (usize, bool) {
+    let is_digit = |byte: u8| {
+        matches!(
+            (byte, radix),
+            (b'0'..=b'1', 2)
+                | (b'0'..=b'7', 8)
+                | (b'0'..=b'9', 10)
+                | (b'0'..=b'9', 16)
+                | (b'a'..=b'f', 16)
+                | (b'A'..=b'F', 16)
+        )
+    };
+    let mut len = 0;
+    let mut has_any_digit = false;
+    if let Some(b'+') | Some(b'-') = bytes.first() {
+        len += 1;
+    }
+    while len < bytes.len() && is_digit(bytes[len]) {
+        has_any_digit = true;
+        len += 1;
+    }
+    if bytes.get(len) == Some(&b'.') {
+        len += 1;
+        while len < bytes.len() && is_digit(bytes[len]) {
+            has_any_digit = true;
+            len += 1;
+        }
+    }
+    (len, has_any_digit)
+}
+
+pub(crate) fn from_str_prefix<F>(src: &str, radix: u32) -> Result<(F, usize), ParseFixedError>
+where
+    F: FromStrRadix<Err = ParseFixedError>,
+{
+    let (len, has_any_digit) = numeric_prefix(src.as_bytes(), radix);
+    if !has_any_digit {
+        return Err(ParseErrorKind::NoDigits.into());
+    }
+    // the prefix is all ASCII, so len is on a char boundary
+    F::from_str_radix(&src[..len], radix).map(|val| (val, len))
+}
+
+// Positions in errors are relative to src, not to the trimmed string.
+pub(crate) fn from_str_trimmed<F>(src: &str) -> Result<F, ParseFixedError>
+where
+    F: FromStrRadix<Err = ParseFixedError>,
+{
+    let start = src.trim_start_matches(|c: char| c.is_ascii_whitespace());
+    let leading = src.len() - start.len();
+    let trimmed = start.trim_end_matches(|c: char| c.is_ascii_whitespace());
+    F::from_str_radix(trimmed, 10).map_err(|err| ParseFixedError {
+        kind: err.kind,
+        position: err.position.map(|p| p + leading),
+    })
+}
+
+// Parses a decimal number followed by '%' and divides it by 100 by
+// moving the point two places to the left, so that the result is
+// rounded only once. Positions in errors are relative to src.
+pub(crate) fn from_str_percent<F>(src: &str) -> Result<F, ParseFixedError>
+where
+    F: FromStrRadix<Err = ParseFixedError>,
+{
+    // 10^41 / 100 = 10^39 does not fit in 128 bits
+    const MAX_INT_LEN: usize = 41;
+    // ties need at most 129 fractional digits for 128 fractional
+    // bits; the digits after those only matter if they are non-zero
+    const MAX_FRAC_LEN: usize = 130;
+    const BUF_LEN: usize = MAX_INT_LEN + MAX_FRAC_LEN + 5;
+
+    let number = match src.as_bytes().split_last() {
+        Some((b'%', number)) => number,
+        _ => return Err(ParseErrorKind::InvalidDigit.into()),
+    };
+    let Parse { neg, int, frac } = parse_bounds(number, 10)?;
+    if int.len() > MAX_INT_LEN {
+        return Err(ParseErrorKind::Overflow.into());
+    }
+    let mut buf = [b'0'; BUF_LEN];
+    let mut len = 0;
+    let mut push = |bytes: &[u8]| {
+        buf[len..len + bytes.len()].copy_from_slice(bytes);
+        len += bytes.len();
+    };
+    if neg {
+        push(b"-");
+    }
+    let split = int.len().saturating_sub(2);
+    push(&int[..split]);
+    push(b".");
+    push(&b"00"[int.len() - split..]);
+    push(&int[split..]);
+    if frac.len() > MAX_FRAC_LEN {
+        push(&frac[..MAX_FRAC_LEN]);
+        if frac[MAX_FRAC_LEN..].iter().any(|&b| b != b'0') {
+            push(b"1");
+        }
+    } else {
+        push(frac);
+    }
+    let shifted = str::from_utf8(&buf[..len]).expect("ASCII digits");
+    F::from_str_radix(shifted, 10)
+}
+
 macro_rules! impl_from_str_traits {
     ($Fixed:ident($Bits:ident), $LeEqU:ident; fn $from:ident) => {
         impl<Frac: $LeEqU> FromStr for $Fixed<Frac> {
             type Err = ParseFixedError;
             /// Parses a string slice to return a fixed-point number.
             ///
-            /// Rounding is to the nearest, with ties rounded to even.
+            /// The number can have a decimal exponent, for example
+            /// `1.5e3` or `1.5E-3`. Rounding is to the nearest, with
+            /// ties rounded to even.
             #[inline]
             fn from_str(s: &str) -> Result<Self, Self::Err> {
                 Self::from_str_radix(s, 10)
             }
         }
+        impl<'a, Frac: $LeEqU> TryFrom<&'a str> for $Fixed<Frac> {
+            type Error = ParseFixedError;
+            /// Parses a string slice to return a fixed-point number.
+            ///
+            /// This is the same as [`FromStr`]; rounding is to the
+            /// nearest, with ties rounded to even.
+            ///
+            /// [`FromStr`]: https://doc.rust-lang.org/nightly/core/str/trait.FromStr.html
+            #[inline]
+            fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+                Self::from_str_radix(s, 10)
+            }
+        }
         impl<Frac: $LeEqU> FromStrRadix for $Fixed<Frac> {
             type Err = ParseFixedError;
             #[inline]
@@ -744,7 +1043,8 @@ macro_rules! impl_from_str {
             int_nbits: u32,
             frac_nbits: u32,
         ) -> Result<(bool, $BitsU, bool), ParseFixedError> {
-            let Parse { neg, int, frac } = parse_bounds(bytes, radix)?;
+            let mut buf = [0; EXP_BUF_LEN];
+            let Parse { neg, int, frac } = parse_bounds_exp(bytes, radix, &mut buf)?;
             let (int_val, mut overflow) = $get_int(int, radix, int_nbits);
             let (frac_val, frac_overflow) = match $get_frac(frac, radix, frac_nbits) {
                 Some(val) => (val, false),
@@ -872,10 +1172,59 @@ mod tests {
         string::{String, ToString},
     };
 
+    #[test]
+    fn trimmed() {
+        assert_eq!(I16F16::from_str_trimmed(" 3.14 "), I16F16::from_str("3.14"));
+        assert_eq!(U8F8::from_str_trimmed("\t\t-0\n"), Ok(U8F8::from_num(0)));
+        assert_eq!(
+            I8F8::from_str_trimmed("\r\n-1.5\x0c"),
+            Ok(I8F8::from_num(-1.5))
+        );
+        // the position is relative to the untrimmed string
+        let err = I16F16::from_str_trimmed(" 1 2 ").unwrap_err();
+        assert_eq!(
+            (err.kind(), err.position()),
+            (ParseErrorKind::InvalidDigit, Some(2))
+        );
+        let err = I16F16::from_str_trimmed(" \t\r\n").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::NoDigits);
+        assert_eq!(
+            I16F16::from_str(" 3.14").unwrap_err().kind(),
+            ParseErrorKind::InvalidDigit
+        );
+    }
+
+    #[test]
+    fn prefix() {
+        assert_eq!(
+            from_str_prefix("1.5abc", 10),
+            Ok((I16F16::from_num(1.5), 3))
+        );
+        assert_eq!(
+            from_str_prefix("-1.5.2", 10),
+            Ok((I16F16::from_num(-1.5), 4))
+        );
+        assert_eq!(from_str_prefix("2. ", 10), Ok((I16F16::from_num(2), 2)));
+        assert_eq!(from_str_prefix(".25", 10), Ok((I16F16::from_num(0.25), 3)));
+        assert_eq!(from_str_prefix("11.12", 2), Ok((I16F16::from_num(3.5), 4)));
+        assert_eq!(
+            from_str_prefix("ff.8g", 16),
+            Ok((I16F16::from_num(255.5), 4))
+        );
+        let no_digits = ParseFixedError::from(ParseErrorKind::NoDigits);
+        assert_eq!(from_str_prefix::<I16F16>("abc", 10), Err(no_digits));
+        assert_eq!(from_str_prefix::<I16F16>("-.x", 10), Err(no_digits));
+        assert_eq!(from_str_prefix::<I16F16>("", 10), Err(no_digits));
+        let overflow = ParseFixedError::from(ParseErrorKind::Overflow);
+        assert_eq!(from_str_prefix::<I8F8>("128;", 10), Err(overflow));
+        assert_eq!(from_str_prefix("-128;", 10), Ok((I8F8::min_value(), 4)));
+    }
+
     #[test]
     fn overflowing() {
         let overflow = ParseFixedError {
             kind: ParseErrorKind::Overflow,
+            position: None,
         };
         assert_eq!(
             U4F4::overflowing_from_str("15.5"),
@@ -958,6 +1307,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn saturating_wrapping() {
+        let invalid = ParseFixedError {
+            kind: ParseErrorKind::InvalidDigit,
+            position: Some(1),
+        };
+        assert_eq!(U8F0::saturating_from_str("300"), Ok(U8F0::from_num(255)));
+        assert_eq!(U8F0::wrapping_from_str("300"), Ok(U8F0::from_num(44)));
+        assert_eq!(U8F0::saturating_from_str("-300"), Ok(U8F0::from_num(0)));
+        assert_eq!(I8F0::saturating_from_str("-300"), Ok(I8F0::from_num(-128)));
+        assert_eq!(I8F0::wrapping_from_str("-300"), Ok(I8F0::from_num(-44)));
+        assert_eq!(U4F4::saturating_from_str("15.5"), Ok(U4F4::from_num(15.5)));
+        assert_eq!(U4F4::saturating_from_str("31.5"), Ok(U4F4::max_value()));
+        assert_eq!(U4F4::wrapping_from_str("31.5"), Ok(U4F4::from_num(15.5)));
+
+        // errors other than overflow are still returned
+        assert_eq!(U8F0::saturating_from_str("3x0"), Err(invalid));
+        assert_eq!(U8F0::wrapping_from_str("3x0"), Err(invalid));
+        assert_eq!(
+            U8F0::saturating_from_str("1.2.3").unwrap_err().kind(),
+            ParseErrorKind::TooManyPoints
+        );
+        assert_eq!(
+            U8F0::wrapping_from_str("").unwrap_err().kind(),
+            ParseErrorKind::NoDigits
+        );
+    }
+
     #[test]
     fn check_dec_8() {
         let two_pow = 8f64.exp2();
@@ -1099,18 +1476,22 @@ mod tests {
         let Parse { neg, int, frac } = parse_bounds(b"-.C1A0", 16).unwrap();
         assert_eq!((neg, int, frac), (true, &b""[..], &b"C1A"[..]));
 
-        let ParseFixedError { kind } = parse_bounds(b"0 ", 10).unwrap_err();
-        assert_eq!(kind, ParseErrorKind::InvalidDigit);
-        let ParseFixedError { kind } = parse_bounds(b"+-", 10).unwrap_err();
-        assert_eq!(kind, ParseErrorKind::InvalidDigit);
-        let ParseFixedError { kind } = parse_bounds(b"+.", 10).unwrap_err();
-        assert_eq!(kind, ParseErrorKind::NoDigits);
-        let ParseFixedError { kind } = parse_bounds(b".1.", 10).unwrap_err();
-        assert_eq!(kind, ParseErrorKind::TooManyPoints);
-        let ParseFixedError { kind } = parse_bounds(b"1+2", 10).unwrap_err();
-        assert_eq!(kind, ParseErrorKind::InvalidDigit);
-        let ParseFixedError { kind } = parse_bounds(b"1-2", 10).unwrap_err();
-        assert_eq!(kind, ParseErrorKind::InvalidDigit);
+        let ParseFixedError { kind, position } = parse_bounds(b"0 ", 10).unwrap_err();
+        assert_eq!((kind, position), (ParseErrorKind::InvalidDigit, Some(1)));
+        let ParseFixedError { kind, position } = parse_bounds(b"+-", 10).unwrap_err();
+        assert_eq!((kind, position), (ParseErrorKind::InvalidDigit, Some(1)));
+        let ParseFixedError { kind, position } = parse_bounds(b"+.", 10).unwrap_err();
+        assert_eq!((kind, position), (ParseErrorKind::NoDigits, None));
+        let ParseFixedError { kind, position } = parse_bounds(b".1.", 10).unwrap_err();
+        assert_eq!((kind, position), (ParseErrorKind::TooManyPoints, Some(2)));
+        let ParseFixedError { kind, position } = parse_bounds(b"1+2", 10).unwrap_err();
+        assert_eq!((kind, position), (ParseErrorKind::InvalidDigit, Some(1)));
+        let ParseFixedError { kind, position } = parse_bounds(b"1-2", 10).unwrap_err();
+        assert_eq!((kind, position), (ParseErrorKind::InvalidDigit, Some(1)));
+        let ParseFixedError { kind, position } = parse_bounds(b"12.3x4", 10).unwrap_err();
+        assert_eq!((kind, position), (ParseErrorKind::InvalidDigit, Some(4)));
+        let ParseFixedError { kind, position } = parse_bounds(b"12.3A4", 10).unwrap_err();
+        assert_eq!((kind, position), (ParseErrorKind::InvalidDigit, Some(4)));
     }
 
     fn assert_ok<F>(s: &str, radix: u32, bits: F::Bits, overflow: bool)
@@ -2031,4 +2412,153 @@ mod tests {
             assert_eq!(I15F17::from_str(&fix_neg_str9).unwrap(), fix_neg);
         }
     }
+
+    #[test]
+    fn hash_errors() {
+        use std::collections::HashSet;
+        let mut set = HashSet::new();
+        set.insert(I16F16::from_str("1x").unwrap_err());
+        set.insert(I16F16::from_str("1x").unwrap_err());
+        set.insert(I16F16::from_str("1.2.3").unwrap_err());
+        set.insert(I16F16::from_str("65536").unwrap_err());
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&I16F16::from_str("1.2.3").unwrap_err()));
+        let kinds: HashSet<ParseErrorKind> = set.iter().map(ParseFixedError::kind).collect();
+        assert_eq!(kinds.len(), 3);
+    }
+
+    #[test]
+    fn try_from_str() {
+        use core::convert::{TryFrom, TryInto};
+        assert_eq!(I16F16::try_from("1.5"), Ok(I16F16::from_num(1.5)));
+        assert_eq!(
+            U8F8::try_from("256").unwrap_err().kind(),
+            ParseErrorKind::Overflow
+        );
+        let parsed: Result<I8F8, _> = "-0.25".try_into();
+        assert_eq!(parsed, Ok(I8F8::from_num(-0.25)));
+        fn generic<T: for<'a> TryFrom<&'a str>>(s: &str) -> Option<T> {
+            T::try_from(s).ok()
+        }
+        assert_eq!(generic::<U16F16>("2.75"), Some(U16F16::from_num(2.75)));
+        assert_eq!(generic::<U16F16>("x"), None);
+    }
+
+    #[test]
+    fn percent() {
+        assert_eq!(U0F16::from_str_percent("12.5%"), Ok(U0F16::from_num(0.125)));
+        assert_eq!(
+            I16F16::from_str_percent("-250%"),
+            Ok(I16F16::from_num(-2.5))
+        );
+        assert_eq!(I16F16::from_str_percent("+5%"), Ok(I16F16::from_num(0.05)));
+        assert_eq!(I16F16::from_str_percent(".5%"), Ok(I16F16::from_num(0.005)));
+        assert_eq!(U8F8::from_str_percent("0%"), Ok(U8F8::from_num(0)));
+        // 0.5% is 1.28 units of 2^-8, so it rounds to 1
+        assert_eq!(U8F8::from_str_percent("0.5%"), Ok(U8F8::from_bits(1)));
+        // 0.1953125% is exactly half of 2^-8, a tie which rounds to even
+        assert_eq!(U8F8::from_str_percent("0.1953125%"), Ok(U8F8::from_bits(0)));
+        assert_eq!(U8F8::from_str_percent("0.5859375%"), Ok(U8F8::from_bits(2)));
+        // a non-zero digit far away breaks the tie
+        let mut long = String::from("0.1953125");
+        long.push_str(&"0".repeat(300));
+        long.push_str("1%");
+        assert_eq!(U8F8::from_str_percent(&long), Ok(U8F8::from_bits(1)));
+        let mut long_int = "1".repeat(50);
+        long_int.push('%');
+        let kind = |r: Result<U0F16, ParseFixedError>| r.unwrap_err().kind();
+        assert_eq!(
+            kind(U0F16::from_str_percent(&long_int)),
+            ParseErrorKind::Overflow
+        );
+        assert_eq!(
+            kind(U0F16::from_str_percent("100%")),
+            ParseErrorKind::Overflow
+        );
+        assert_eq!(
+            kind(U0F16::from_str_percent("12.5")),
+            ParseErrorKind::InvalidDigit
+        );
+        assert_eq!(kind(U0F16::from_str_percent("%")), ParseErrorKind::NoDigits);
+        let err = I8F8::from_str_percent("1x%").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::InvalidDigit);
+        assert_eq!(
+            format!("{}", err),
+            format!("{}", I8F8::from_str("1x").unwrap_err())
+        );
+        assert_eq!(
+            I8F8::from_str_percent("50000%").unwrap_err().kind(),
+            ParseErrorKind::Overflow
+        );
+    }
+
+    #[test]
+    fn exponent() {
+        let kind = |r: Result<I16F16, ParseFixedError>| r.unwrap_err().kind();
+        assert_eq!(I16F16::from_str("1.5e3"), Ok(I16F16::from_num(1500)));
+        assert_eq!(I16F16::from_str("1.5E3"), Ok(I16F16::from_num(1500)));
+        assert_eq!(I16F16::from_str("15e-1"), Ok(I16F16::from_num(1.5)));
+        assert_eq!(I16F16::from_str("-1.25e-1"), Ok(I16F16::from_num(-0.125)));
+        assert_eq!(I16F16::from_str("+2.5e+2"), Ok(I16F16::from_num(250)));
+        assert_eq!(I16F16::from_str(".5e1"), Ok(I16F16::from_num(5)));
+        assert_eq!(I16F16::from_str("5.e-1"), Ok(I16F16::from_num(0.5)));
+        assert_eq!(I16F16::from_str("0.000e5"), Ok(I16F16::from_num(0)));
+        assert_eq!(I16F16::from_str("-0e0"), Ok(I16F16::from_num(0)));
+        assert_eq!(I16F16::from_str("0012.5e0003"), I16F16::from_str("12500"));
+        assert_eq!(I16F16::from_str("123.456e-2"), I16F16::from_str("1.23456"));
+        assert_eq!(I16F16::from_str("0.00123e2"), I16F16::from_str("0.123"));
+        assert_eq!(I16F16::from_str("1200e-2"), Ok(I16F16::from_num(12)));
+        assert_eq!(U0F32::from_str("1e-6"), U0F32::from_str("0.000001"));
+
+        // the value is rounded only once
+        assert_eq!(U8F8::from_str("1.953125e-3"), Ok(U8F8::from_bits(0)));
+        assert_eq!(U8F8::from_str("5.859375e-3"), Ok(U8F8::from_bits(2)));
+        let mut long = String::from("1953125");
+        long.push_str(&"0".repeat(300));
+        long.push_str("1e-310");
+        assert_eq!(U8F8::from_str(&long), Ok(U8F8::from_bits(1)));
+        assert_eq!(U8F8::from_str("1e-1000"), Ok(U8F8::from_num(0)));
+        assert_eq!(
+            I16F16::from_str("-1e-99999999999999999999"),
+            Ok(I16F16::from_num(0))
+        );
+
+        assert_eq!(kind(I16F16::from_str("3.2768e4")), ParseErrorKind::Overflow);
+        assert_eq!(
+            kind(I16F16::from_str("1e99999999999999999999")),
+            ParseErrorKind::Overflow
+        );
+        assert_eq!(U8F8::wrapping_from_str("1e3"), Ok(U8F8::from_num(232)));
+        assert_eq!(U8F8::saturating_from_str("1e3"), Ok(U8F8::max_value()));
+        assert_eq!(
+            U128F0::overflowing_from_str("1e200"),
+            Ok((U128F0::from_num(0), true))
+        );
+        assert_eq!(
+            U128F0::overflowing_from_str("3.40282366920938463463374607431768211455e38"),
+            Ok((U128F0::max_value(), false))
+        );
+        assert_eq!(
+            U128F0::overflowing_from_str("3.40282366920938463463374607431768211456e38"),
+            Ok((U128F0::from_num(0), true))
+        );
+
+        assert_eq!(kind(I16F16::from_str("1e")), ParseErrorKind::InvalidDigit);
+        assert_eq!(I16F16::from_str("1e").unwrap_err().position(), Some(1));
+        assert_eq!(I16F16::from_str("1e+").unwrap_err().position(), Some(1));
+        assert_eq!(I16F16::from_str("1e5x").unwrap_err().position(), Some(3));
+        assert_eq!(I16F16::from_str("1e1.5").unwrap_err().position(), Some(3));
+        assert_eq!(I16F16::from_str("1.2e3e4").unwrap_err().position(), Some(5));
+        assert_eq!(kind(I16F16::from_str("e5")), ParseErrorKind::NoDigits);
+        assert_eq!(
+            kind(I16F16::from_str("1.2.3e4")),
+            ParseErrorKind::TooManyPoints
+        );
+        // e is a digit in hexadecimal
+        assert_eq!(U8F8::from_str_hex("e"), Ok(U8F8::from_num(14)));
+        assert_eq!(
+            kind(I16F16::from_str_binary("1e1")),
+            ParseErrorKind::InvalidDigit
+        );
+    }
 }
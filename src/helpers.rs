@@ -34,6 +34,37 @@ pub struct ToFixedHelper {
     pub(crate) overflow: bool,
 }
 
+// Nearest rounds ties to even; the others are directed roundings.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Round {
+    Nearest,
+    Floor,
+    Ceil,
+    Trunc,
+}
+
+impl ToFixedHelper {
+    // Adds one lsb to bits that were truncated towards −∞. The bits
+    // must be exact, which is the case whenever bits were lost.
+    pub(crate) fn increment(self, dst_nbits: u32) -> ToFixedHelper {
+        let bits = match self.bits {
+            Widest::Unsigned(bits) => Widest::Unsigned(bits + 1),
+            Widest::Negative(-1) => Widest::Unsigned(0),
+            Widest::Negative(bits) => Widest::Negative(bits + 1),
+        };
+        let overflow = dst_nbits < 128
+            && match bits {
+                Widest::Unsigned(bits) => bits >> dst_nbits != 0,
+                Widest::Negative(bits) => bits < -(1 << (dst_nbits - 1)),
+            };
+        ToFixedHelper {
+            bits,
+            dir: Ordering::Greater,
+            overflow,
+        }
+    }
+}
+
 pub struct ToFloatHelper {
     pub(crate) neg: bool,
     pub(crate) abs: u128,
@@ -0,0 +1,68 @@
+// Copyright © 2018–2019 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::{
+    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
+    FixedU8,
+};
+use bytemuck::{Pod, Zeroable};
+
+// The fixed-point types are #[repr(transparent)] wrappers around a
+// primitive integer, and PhantomData<Frac> is zero-sized, so every bit
+// pattern is valid and there is no padding.
+macro_rules! impl_pod {
+    ($Fixed:ident) => {
+        unsafe impl<Frac> Zeroable for $Fixed<Frac> {}
+
+        unsafe impl<Frac: 'static> Pod for $Fixed<Frac> {}
+    };
+}
+
+impl_pod! { FixedI8 }
+impl_pod! { FixedI16 }
+impl_pod! { FixedI32 }
+impl_pod! { FixedI64 }
+impl_pod! { FixedI128 }
+impl_pod! { FixedU8 }
+impl_pod! { FixedU16 }
+impl_pod! { FixedU32 }
+impl_pod! { FixedU64 }
+impl_pod! { FixedU128 }
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{I16F16, U8F8};
+    use bytemuck::Zeroable;
+
+    #[cfg(target_endian = "little")]
+    #[test]
+    fn cast_slice() {
+        let a = [I16F16::from_num(1.5), I16F16::from_num(-2)];
+        let bytes: &[u8] = bytemuck::cast_slice(&a);
+        assert_eq!(bytes, [0x00, 0x80, 0x01, 0x00, 0x00, 0x00, 0xFE, 0xFF]);
+        let back: &[I16F16] = bytemuck::cast_slice(bytes);
+        assert_eq!(back, a);
+    }
+
+    #[test]
+    fn cast_single() {
+        let a = U8F8::from_num(3.25);
+        let bits: u16 = bytemuck::cast(a);
+        assert_eq!(bits, a.to_bits());
+        assert_eq!(bytemuck::cast::<u16, U8F8>(bits), a);
+        assert_eq!(bytemuck::cast::<U8F8, [u8; 2]>(a), a.to_ne_bytes());
+        assert_eq!(I16F16::zeroed(), 0);
+    }
+}
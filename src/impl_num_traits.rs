@@ -0,0 +1,129 @@
+// Copyright © 2018–2019 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::{
+    types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8},
+    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
+    FixedU8, ParseFixedError,
+};
+use num_traits::{Num, One, Zero};
+
+macro_rules! impl_traits {
+    ($Fixed:ident, $LeEqU:ident) => {
+        impl<Frac: $LeEqU> Zero for $Fixed<Frac> {
+            #[inline]
+            fn zero() -> Self {
+                Self::from_bits(0)
+            }
+            #[inline]
+            fn is_zero(&self) -> bool {
+                self.to_bits() == 0
+            }
+        }
+
+        impl<Frac: $LeEqU> One for $Fixed<Frac> {
+            /// Returns one.
+            ///
+            /// # Panics
+            ///
+            /// Panics if one is not representable, that is if there are
+            /// not enough integer bits to hold it.
+            #[inline]
+            fn one() -> Self {
+                Self::checked_from_num(1).expect("one is not representable")
+            }
+        }
+
+        impl<Frac: $LeEqU> Num for $Fixed<Frac> {
+            type FromStrRadixErr = ParseFixedError;
+
+            /// Parses a string slice containing digits in the given radix.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `radix` is not 2, 8, 10 or 16.
+            #[inline]
+            fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseFixedError> {
+                Self::from_str_radix(src, radix)
+            }
+        }
+    };
+}
+
+impl_traits! { FixedI8, LeEqU8 }
+impl_traits! { FixedI16, LeEqU16 }
+impl_traits! { FixedI32, LeEqU32 }
+impl_traits! { FixedI64, LeEqU64 }
+impl_traits! { FixedI128, LeEqU128 }
+impl_traits! { FixedU8, LeEqU8 }
+impl_traits! { FixedU16, LeEqU16 }
+impl_traits! { FixedU32, LeEqU32 }
+impl_traits! { FixedU64, LeEqU64 }
+impl_traits! { FixedU128, LeEqU128 }
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{I16F16, I1F7, I2F6, I8F8, U0F8, U1F7, U64F64};
+    use num_traits::{Num, One, Zero};
+
+    #[test]
+    fn zero_one() {
+        assert_eq!(I8F8::zero(), I8F8::from_num(0));
+        assert!(I8F8::zero().is_zero());
+        assert!(!I8F8::from_bits(1).is_zero());
+        assert!(U0F8::zero().is_zero());
+        assert_eq!(I8F8::one(), I8F8::from_num(1));
+        assert!(I8F8::one().is_one());
+        assert_eq!(I2F6::one(), I2F6::from_num(1));
+        assert_eq!(U1F7::one(), U1F7::from_num(1));
+        assert_eq!(U64F64::one() * U64F64::from_num(2.5), U64F64::from_num(2.5));
+    }
+
+    #[test]
+    #[should_panic(expected = "one is not representable")]
+    fn one_unsigned_no_int_bits() {
+        let _ = U0F8::one();
+    }
+
+    #[test]
+    #[should_panic(expected = "one is not representable")]
+    fn one_signed_one_int_bit() {
+        let _ = I1F7::one();
+    }
+
+    fn sum<T: Num + Copy>(vals: &[T]) -> T {
+        vals.iter().fold(T::zero(), |acc, &x| acc + x)
+    }
+
+    #[test]
+    fn num() {
+        let vals = [
+            I16F16::from_num(1.5),
+            I16F16::from_num(-0.25),
+            I16F16::from_num(3),
+        ];
+        assert_eq!(sum(&vals), I16F16::from_num(4.25));
+        assert_eq!(sum::<I16F16>(&[]), I16F16::from_num(0));
+        assert_eq!(
+            <I16F16 as Num>::from_str_radix("-11.01", 2),
+            Ok(I16F16::from_num(-3.25))
+        );
+        assert_eq!(
+            <U64F64 as Num>::from_str_radix("ff.8", 16),
+            Ok(U64F64::from_num(255.5))
+        );
+        assert!(<I8F8 as Num>::from_str_radix("1000", 10).is_err());
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright © 2018–2019 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::{
+    types::extra::Unsigned, FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16,
+    FixedU32, FixedU64, FixedU8,
+};
+use core::marker::PhantomData;
+use scale_info::{build::Fields, meta_type, Path, Type, TypeDefArray, TypeInfo, TypeParameter};
+
+// The typenum types used for Frac do not implement TypeInfo, so the
+// number of fractional bits is described as the length of an array of
+// units. This keeps types that only differ in Frac apart.
+struct FracBits<Frac>(PhantomData<Frac>);
+
+impl<Frac: Unsigned + 'static> TypeInfo for FracBits<Frac> {
+    type Identity = Self;
+
+    fn type_info() -> Type {
+        TypeDefArray::new(Frac::U32, meta_type::<()>()).into()
+    }
+}
+
+macro_rules! impl_type_info {
+    ($Fixed:ident($Inner:ident)) => {
+        impl<Frac: Unsigned + 'static> TypeInfo for $Fixed<Frac> {
+            type Identity = Self;
+
+            fn type_info() -> Type {
+                Type::builder()
+                    .path(Path::new(stringify!($Fixed), "substrate_fixed"))
+                    .type_params([TypeParameter::new(
+                        "Frac",
+                        Some(meta_type::<FracBits<Frac>>()),
+                    )])
+                    .composite(
+                        Fields::named()
+                            .field(|f| f.ty::<$Inner>().name("bits").type_name(stringify!($Inner))),
+                    )
+            }
+        }
+    };
+}
+
+impl_type_info! { FixedI8(i8) }
+impl_type_info! { FixedI16(i16) }
+impl_type_info! { FixedI32(i32) }
+impl_type_info! { FixedI64(i64) }
+impl_type_info! { FixedI128(i128) }
+impl_type_info! { FixedU8(u8) }
+impl_type_info! { FixedU16(u16) }
+impl_type_info! { FixedU32(u32) }
+impl_type_info! { FixedU64(u64) }
+impl_type_info! { FixedU128(u128) }
+
+#[cfg(test)]
+mod tests {
+    use super::FracBits;
+    use crate::{
+        types::{
+            extra::{U16, U8},
+            I16F16, U0F128, U8F8,
+        },
+        FixedI32,
+    };
+    use scale_info::{meta_type, TypeDef, TypeInfo};
+
+    fn check<F: TypeInfo, Bits: TypeInfo + 'static>(name: &str, bits: &str, frac_nbits: u32) {
+        let info = F::type_info();
+        assert_eq!(info.path().segments(), ["substrate_fixed", name]);
+        assert_eq!(info.type_params().len(), 1);
+        assert_eq!(info.type_params()[0].name(), &"Frac");
+        let frac = info.type_params()[0].ty().expect("Frac has no type");
+        match frac.type_info().type_def() {
+            TypeDef::Array(array) => {
+                assert_eq!(array.len(), frac_nbits);
+                assert_eq!(array.type_param(), &meta_type::<()>());
+            }
+            _ => panic!("Frac is not an array"),
+        }
+        let fields = match info.type_def() {
+            TypeDef::Composite(composite) => composite.fields(),
+            _ => panic!("not a composite"),
+        };
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].name(), Some(&"bits"));
+        assert_eq!(fields[0].ty(), &meta_type::<Bits>());
+        assert_eq!(fields[0].type_name(), Some(&bits));
+    }
+
+    #[test]
+    fn type_info() {
+        check::<I16F16, i32>("FixedI32", "i32", 16);
+        check::<U8F8, u16>("FixedU16", "u16", 8);
+        check::<U0F128, u128>("FixedU128", "u128", 128);
+    }
+
+    #[test]
+    fn frac_in_type_info() {
+        let info16 = FixedI32::<U16>::type_info();
+        let info8 = FixedI32::<U8>::type_info();
+        assert_ne!(info16, info8);
+        assert_eq!(info16.path(), info8.path());
+        assert_eq!(
+            info16.type_params()[0].ty(),
+            Some(&meta_type::<FracBits<U16>>())
+        );
+        assert_ne!(meta_type::<FixedI32<U16>>(), meta_type::<FixedI32<U8>>());
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright © 2018–2019 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::{
+    types::extra::U0, FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16,
+    FixedU32, FixedU64, FixedU8,
+};
+use core::iter::Step;
+
+// With no fractional bits one step is both one and one ULP, so
+// stepping is exactly stepping the underlying integer.
+macro_rules! impl_step {
+    ($Fixed:ident, $Inner:ty) => {
+        impl Step for $Fixed<U0> {
+            #[inline]
+            fn steps_between(start: &Self, end: &Self) -> (usize, Option<usize>) {
+                <$Inner as Step>::steps_between(&start.to_bits(), &end.to_bits())
+            }
+
+            #[inline]
+            fn forward_checked(start: Self, count: usize) -> Option<Self> {
+                <$Inner as Step>::forward_checked(start.to_bits(), count).map(Self::from_bits)
+            }
+
+            #[inline]
+            fn backward_checked(start: Self, count: usize) -> Option<Self> {
+                <$Inner as Step>::backward_checked(start.to_bits(), count).map(Self::from_bits)
+            }
+        }
+    };
+}
+
+impl_step! { FixedI8, i8 }
+impl_step! { FixedI16, i16 }
+impl_step! { FixedI32, i32 }
+impl_step! { FixedI64, i64 }
+impl_step! { FixedI128, i128 }
+impl_step! { FixedU8, u8 }
+impl_step! { FixedU16, u16 }
+impl_step! { FixedU32, u32 }
+impl_step! { FixedU64, u64 }
+impl_step! { FixedU128, u128 }
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{I8F0, U128F0, U16F0};
+    use std::vec::Vec;
+
+    #[test]
+    fn range() {
+        let values: Vec<I8F0> = (I8F0::from_num(0)..I8F0::from_num(3)).collect();
+        assert_eq!(values, [0, 1, 2]);
+        let values: Vec<I8F0> = (I8F0::from_num(-2)..=I8F0::from_num(1)).rev().collect();
+        assert_eq!(values, [1, 0, -1, -2]);
+        assert_eq!((I8F0::min_value()..=I8F0::max_value()).count(), 256);
+        assert_eq!((U16F0::from_num(5)..U16F0::from_num(5)).count(), 0);
+        assert_eq!(
+            (U16F0::from_num(10)..U16F0::from_num(1000))
+                .step_by(10)
+                .count(),
+            99
+        );
+        assert_eq!(
+            (U128F0::from_num(0)..U128F0::max_value()).size_hint(),
+            (usize::MAX, None)
+        );
+    }
+}
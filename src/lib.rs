@@ -44,6 +44,18 @@ The main features are
   * Display as decimal, binary, octal and hexadecimal.
   * Arithmetic and logic operations.
 
+The logic operators [`Not`], [`BitAnd`], [`BitOr`] and [`BitXor`], and
+their assignment versions, operate on the underlying two's-complement
+bit pattern and not on the numeric value. For example, masking a
+fixed-point number with `&` is the same as masking its [`to_bits`].
+
+The arithmetic operators `+`, `-`, `*`, `/` and `%`, and their
+assignment versions, also accept the underlying integer type as the
+right-hand operand, with its numeric value. For example, a [`FixedI32`]
+can be added to an [`i32`], so `I16F16::from_num(1.5) + 2` is 3.5. Only
+that exact integer type is accepted, so integer literals are never
+ambiguous.
+
 This crate does *not* provide general analytic functions.
 
   * No algebraic functions are provided, for example no `sqrt` or
@@ -68,6 +80,10 @@ The conversions supported cover the following cases.
     numeric primitives are provided using the [`LossyFrom`] and
     [`LossyInto`] traits. The source can have more fractional bits
     than the destination.
+  * Fallible exact conversions from wider to narrower fixed-point
+    numbers of the same signedness are provided using [`TryFrom`].
+    These fail with a [`TryFromFixedError`] if the value overflows or
+    if fractional bits would be lost.
   * Checked conversions between fixed-point numbers and numeric
     primitives are provided using the [`FromFixed`] and [`ToFixed`]
     traits, or using the [`from_num`] and [`to_num`] methods and
@@ -75,11 +91,18 @@ The conversions supported cover the following cases.
   * Fixed-point numbers can be parsed from decimal strings using
     [`FromStr`], and from binary, octal and hexadecimal strings using
     the [`from_str_binary`], [`from_str_octal`] and [`from_str_hex`]
-    methods. The result is rounded to the nearest, with ties rounded
-    to even.
+    methods. Decimal strings can have an exponent such as `1.5e3`.
+    The result is rounded to the nearest, with ties rounded to even.
   * Fixed-point numbers can be converted to strings using [`Display`],
-    [`Binary`], [`Octal`], [`LowerHex`] and [`UpperHex`]. The output
-    is rounded to the nearest, with ties rounded to even.
+    [`Binary`], [`Octal`], [`LowerHex`], [`UpperHex`], [`LowerExp`]
+    and [`UpperExp`]. The output is rounded to the nearest, with
+    ties rounded to even. Negative numbers are always formatted as a
+    minus sign followed by the magnitude, even in binary, octal and
+    hexadecimal, unlike the two's complement output of primitive
+    signed integers.
+  * Fixed-point numbers implement the SCALE codec traits `Encode`,
+    `Decode` and `MaxEncodedLen` from the [*parity-scale-codec*
+    crate], and are encoded exactly like their underlying integer.
 
 ## Quick examples
 
@@ -150,16 +173,36 @@ The *fixed* crate requires rustc version 1.39.0 or later.
 
 ## Optional features
 
-The *fixed* crate has four optional feature:
+The *fixed* crate has nine optional features:
 
  1. `az`, disabled by default. This implements the cast traits
     provided by the [*az* crate].
- 2. `f16`, disabled by default. This provides conversion to/from
+ 2. `bytemuck`, disabled by default. This implements the [`Pod`]
+    and [`Zeroable`] traits from the [*bytemuck* crate] so that
+    slices of fixed-point numbers can be reinterpreted as bytes
+    without copying.
+ 3. `f16`, disabled by default. This provides conversion to/from
     [`f16`] and [`bf16`]. This features requires the [*half* crate].
- 3. `serde`, disabled by default. This provides serialization support
+ 4. `num-traits`, disabled by default. This implements some traits
+    from the [*num-traits* crate]. [`One::one`] panics for types
+    that cannot represent one, such as [`U0F8`].
+ 5. `scale-info`, disabled by default. This implements the
+    `TypeInfo` trait from the [*scale-info* crate] so that the
+    fixed-point types can be described in runtime metadata. The
+    `Frac` type parameter is described as an array of units with one
+    element for each fractional bit.
+ 6. `serde`, disabled by default. This provides serialization support
     for the fixed-point types. This feature requires the
     [*serde* crate].
- 4. `std`, disabled by default. This is for features that are not
+ 7. `serde-str`, disabled by default. Fixed-point numbers are
+    serialized as strings showing the value when using human-readable
+    formats such as JSON, and as their underlying integer otherwise.
+    This feature enables the `serde` feature.
+ 8. `step_trait`, disabled by default. This implements the unstable
+    [`Step`] trait for fixed-point numbers with no fractional bits,
+    so that they can be used in ranges such as `a..b`. This feature
+    requires the nightly compiler.
+ 9. `std`, disabled by default. This is for features that are not
     possible under `no_std`: currently the implementation of the
     [`Error`] trait for [`ParseFixedError`].
 
@@ -191,14 +234,21 @@ additional terms or conditions.
 
 [*Cargo.toml*]: https://doc.rust-lang.org/cargo/guide/dependencies.html
 [*az* crate]: https://crates.io/crates/az
+[*bytemuck* crate]: https://crates.io/crates/bytemuck
 [*fixed* crate]: https://crates.io/crates/fixed
 [*fixed-sqrt* crate]: https://crates.io/crates/fixed-sqrt
 [*half* crate]: https://crates.io/crates/half
+[*num-traits* crate]: https://crates.io/crates/num-traits
+[*parity-scale-codec* crate]: https://crates.io/crates/parity-scale-codec
+[*scale-info* crate]: https://crates.io/crates/scale-info
 [*serde* crate]: https://crates.io/crates/serde
 [*typenum* crate]: https://crates.io/crates/typenum
 [LICENSE-APACHE]: https://www.apache.org/licenses/LICENSE-2.0
 [LICENSE-MIT]: https://opensource.org/licenses/MIT
 [`Binary`]: https://doc.rust-lang.org/nightly/core/fmt/trait.Binary.html
+[`BitAnd`]: https://doc.rust-lang.org/nightly/core/ops/trait.BitAnd.html
+[`BitOr`]: https://doc.rust-lang.org/nightly/core/ops/trait.BitOr.html
+[`BitXor`]: https://doc.rust-lang.org/nightly/core/ops/trait.BitXor.html
 [`Display`]: https://doc.rust-lang.org/nightly/core/fmt/trait.Display.html
 [`Error`]: https://doc.rust-lang.org/nightly/std/error/trait.Error.html
 [`FixedI128`]: struct.FixedI128.html
@@ -220,13 +270,23 @@ additional terms or conditions.
 [`Into`]: https://doc.rust-lang.org/nightly/core/convert/trait.Into.html
 [`LossyFrom`]: traits/trait.LossyFrom.html
 [`LossyInto`]: traits/trait.LossyInto.html
+[`LowerExp`]: https://doc.rust-lang.org/nightly/core/fmt/trait.LowerExp.html
 [`LowerHex`]: https://doc.rust-lang.org/nightly/core/fmt/trait.LowerHex.html
+[`Not`]: https://doc.rust-lang.org/nightly/core/ops/trait.Not.html
 [`Octal`]: https://doc.rust-lang.org/nightly/core/fmt/trait.Octal.html
+[`One::one`]: https://docs.rs/num-traits/^0.2/num_traits/identities/trait.One.html#tymethod.one
 [`ParseFixedError`]: struct.ParseFixedError.html
+[`Pod`]: https://docs.rs/bytemuck/^1/bytemuck/trait.Pod.html
+[`Step`]: https://doc.rust-lang.org/nightly/core/iter/trait.Step.html
 [`ToFixed`]: traits/trait.ToFixed.html
+[`TryFrom`]: https://doc.rust-lang.org/nightly/core/convert/trait.TryFrom.html
+[`TryFromFixedError`]: struct.TryFromFixedError.html
+[`U0F8`]: types/type.U0F8.html
 [`U12`]: types/extra/type.U12.html
 [`U20F12`]: types/type.U20F12.html
+[`UpperExp`]: https://doc.rust-lang.org/nightly/core/fmt/trait.UpperExp.html
 [`UpperHex`]: https://doc.rust-lang.org/nightly/core/fmt/trait.UpperHex.html
+[`Zeroable`]: https://docs.rs/bytemuck/^1/bytemuck/trait.Zeroable.html
 [`bf16`]: https://docs.rs/half/^1/half/struct.bf16.html
 [`checked_from_num`]: struct.FixedI32.html#method.checked_from_num
 [`f16`]: https://docs.rs/half/^1/half/struct.f16.html
@@ -234,10 +294,13 @@ additional terms or conditions.
 [`from_str_binary`]: struct.FixedI32.html#method.from_str_binary
 [`from_str_hex`]: struct.FixedI32.html#method.from_str_hex
 [`from_str_octal`]: struct.FixedI32.html#method.from_str_octal
+[`i32`]: https://doc.rust-lang.org/nightly/std/primitive.i32.html
+[`to_bits`]: struct.FixedI32.html#method.to_bits
 [`to_num`]: struct.FixedI32.html#method.to_num
 [const generics]: https://github.com/rust-lang/rust/issues/44580
 */
 #![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "step_trait", feature(step_trait))]
 #![warn(missing_docs)]
 #![doc(html_root_url = "https://docs.rs/fixed/0.5.4")]
 #![doc(test(attr(deny(warnings))))]
@@ -260,7 +323,17 @@ mod display;
 mod float_helper;
 mod from_str;
 mod helpers;
+#[cfg(feature = "bytemuck")]
+mod impl_bytemuck;
+#[cfg(feature = "num-traits")]
+mod impl_num_traits;
+#[cfg(feature = "scale-info")]
+mod impl_scale_info;
+#[cfg(feature = "step_trait")]
+mod impl_step;
 mod int_helper;
+mod range;
+mod saturating;
 #[cfg(feature = "serde")]
 mod serdeize;
 pub mod traits;
@@ -269,19 +342,33 @@ pub mod types;
 mod wide_div;
 mod wrapping;
 
+pub use crate::{
+    arith::DivError,
+    convert::{FromNumError, Inexact, TryFromFixedError},
+    from_str::{ParseErrorKind, ParseFixedError},
+    range::{range, FixedRange},
+    saturating::Saturating,
+    wrapping::Wrapping,
+};
 use crate::{
     arith::MulDivOverflow,
     from_str::FromStrRadix,
+    helpers::Round,
     traits::{FromFixed, ToFixed},
-    types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8},
+    types::{
+        extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8},
+        U64F64,
+    },
 };
-pub use crate::{from_str::ParseFixedError, wrapping::Wrapping};
 use core::{
     cmp::Ordering,
     hash::{Hash, Hasher},
     marker::PhantomData,
     mem,
+    time::Duration,
 };
+#[cfg(feature = "f16")]
+use half::f16;
 
 /// A prelude for users of the *fixed* crate.
 ///
@@ -311,19 +398,19 @@ mod macros_no_frac;
 #[macro_use]
 mod macros_frac;
 
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
 macro_rules! fixed {
     (
         $description:expr,
         $Fixed:ident($Inner:ty, $LeEqU:tt, $s_nbits:expr, $s_nbits_m4:expr),
         $nbytes:expr, $bytes_val:expr, $be_bytes:expr, $le_bytes:expr,
-        $UInner:ty, $Signedness:tt
+        $UFixed:ident($UInner:ty), $Signedness:tt
     ) => {
         fixed! {
             $description,
             $Fixed[stringify!($Fixed)]($Inner[stringify!($Inner)], $LeEqU, $s_nbits, $s_nbits_m4),
             $nbytes, $bytes_val, $be_bytes, $le_bytes,
-            $UInner, $Signedness
+            $UFixed($UInner), $Signedness
         }
     };
     (
@@ -332,7 +419,7 @@ macro_rules! fixed {
             $Inner:ty[$s_inner:expr], $LeEqU:tt, $s_nbits:expr, $s_nbits_m4:expr
         ),
         $nbytes:expr, $bytes_val:expr, $be_bytes:expr, $le_bytes:expr,
-        $UInner:ty, $Signedness:tt
+        $UFixed:ident($UInner:ty), $Signedness:tt
     ) => {
         comment! {
             $description,
@@ -390,6 +477,8 @@ assert_eq!(two_point_75.to_string(), \"2.8\");
             }
         }
 
+        // Every value has exactly one representation, so hashing the bits
+        // guarantees that a == b implies hash(a) == hash(b).
         impl<Frac> Hash for $Fixed<Frac> {
             #[inline]
             fn hash<H: Hasher>(&self, state: &mut H) {
@@ -397,18 +486,25 @@ assert_eq!(two_point_75.to_string(), \"2.8\");
             }
         }
 
+        impl<Frac> MaxEncodedLen for $Fixed<Frac> {
+            #[inline]
+            fn max_encoded_len() -> usize {
+                mem::size_of::<$Inner>()
+            }
+        }
+
         // inherent methods that do not require Frac bounds, some of which can thus be const
         fixed_no_frac! {
             $description,
             $Fixed[$s_fixed]($Inner[$s_inner], $s_nbits),
             $nbytes, $bytes_val, $be_bytes, $le_bytes,
-            $UInner, $Signedness
+            $UFixed($UInner), $Signedness
         }
         // inherent methods that require Frac bounds, and cannot be const
         fixed_frac! {
             $description,
             $Fixed[$s_fixed]($Inner[$s_inner], $LeEqU, $s_nbits, $s_nbits_m4),
-            $UInner, $Signedness
+            $UFixed($UInner), $Signedness
         }
     };
 }
@@ -417,19 +513,19 @@ fixed! {
     "An eight-bit fixed-point unsigned",
     FixedU8(u8, LeEqU8, "8", "4"),
     1, "0x12", "[0x12]", "[0x12]",
-    u8, Unsigned
+    FixedU8(u8), Unsigned
 }
 fixed! {
     "A 16-bit fixed-point unsigned",
     FixedU16(u16, LeEqU16, "16", "12"),
     2, "0x1234", "[0x12, 0x34]", "[0x34, 0x12]",
-    u16, Unsigned
+    FixedU16(u16), Unsigned
 }
 fixed! {
     "A 32-bit fixed-point unsigned",
     FixedU32(u32, LeEqU32, "32", "28"),
     4, "0x1234_5678", "[0x12, 0x34, 0x56, 0x78]", "[0x78, 0x56, 0x34, 0x12]",
-    u32, Unsigned
+    FixedU32(u32), Unsigned
 }
 fixed! {
     "A 64-bit fixed-point unsigned",
@@ -437,7 +533,7 @@ fixed! {
     8, "0x1234_5678_9ABC_DEF0",
     "[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0]",
     "[0xF0, 0xDE, 0xBC, 0x9A, 0x78, 0x56, 0x34, 0x12]",
-    u64, Unsigned
+    FixedU64(u64), Unsigned
 }
 fixed! {
     "A 128-bit fixed-point unsigned",
@@ -447,25 +543,25 @@ fixed! {
      0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0]",
     "[0xF0, 0xDE, 0xBC, 0x9A, 0x78, 0x56, 0x34, 0x12, \
      0xF0, 0xDE, 0xBC, 0x9A, 0x78, 0x56, 0x34, 0x12]",
-    u128, Unsigned
+    FixedU128(u128), Unsigned
 }
 fixed! {
     "An eight-bit fixed-point signed",
     FixedI8(i8, LeEqU8, "8", "4"),
     1, "0x12", "[0x12]", "[0x12]",
-    u8, Signed
+    FixedU8(u8), Signed
 }
 fixed! {
     "A 16-bit fixed-point signed",
     FixedI16(i16, LeEqU16, "16", "12"),
     2, "0x1234", "[0x12, 0x34]", "[0x34, 0x12]",
-    u16, Signed
+    FixedU16(u16), Signed
 }
 fixed! {
     "A 32-bit fixed-point signed",
     FixedI32(i32, LeEqU32, "32", "28"),
     4, "0x1234_5678", "[0x12, 0x34, 0x56, 0x78]", "[0x78, 0x56, 0x34, 0x12]",
-    u32, Signed
+    FixedU32(u32), Signed
 }
 fixed! {
     "A 64-bit fixed-point signed",
@@ -473,7 +569,7 @@ fixed! {
     8, "0x1234_5678_9ABC_DEF0",
     "[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0]",
     "[0xF0, 0xDE, 0xBC, 0x9A, 0x78, 0x56, 0x34, 0x12]",
-    u64, Signed
+    FixedU64(u64), Signed
 }
 fixed! {
     "A 128-bit fixed-point signed",
@@ -483,7 +579,7 @@ fixed! {
      0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0]",
     "[0xF0, 0xDE, 0xBC, 0x9A, 0x78, 0x56, 0x34, 0x12, \
      0xF0, 0xDE, 0xBC, 0x9A, 0x78, 0x56, 0x34, 0x12]",
-    u128, Signed
+    FixedU128(u128), Signed
 }
 
 #[cfg(test)]
@@ -1076,4 +1172,1792 @@ mod tests {
             (U16F16::from_num(4), false)
         );
     }
+
+    #[test]
+    fn quantized_round_trip() {
+        use crate::types::{I8F8, U0F32};
+
+        let (lo, hi) = (I8F8::from_num(-2), I8F8::from_num(3.5));
+        for code in 0..16 {
+            let center = I8F8::from_quantized::<4>(code, lo, hi);
+            assert!(lo <= center && center <= hi);
+            assert_eq!(center.to_quantized::<4>(lo, hi), code);
+        }
+        assert_eq!(
+            I8F8::from_quantized::<4>(99, lo, hi),
+            I8F8::from_quantized::<4>(15, lo, hi)
+        );
+        assert_eq!(I8F8::min_value().to_quantized::<4>(lo, hi), 0);
+        assert_eq!(I8F8::max_value().to_quantized::<4>(lo, hi), 15);
+
+        let (lo, hi) = (I8F8::min_value(), I8F8::max_value());
+        for code in 0..256 {
+            let center = I8F8::from_quantized::<8>(code, lo, hi);
+            assert_eq!(center.to_quantized::<8>(lo, hi), code);
+        }
+
+        let (lo, hi) = (U0F32::from_num(0.25), U0F32::from_num(0.75));
+        for code in 0..256 {
+            let center = U0F32::from_quantized::<8>(code, lo, hi);
+            assert_eq!(center.to_quantized::<8>(lo, hi), code);
+        }
+        assert_eq!(lo.to_quantized::<8>(lo, lo), 0);
+    }
+
+    #[test]
+    fn min_max() {
+        use crate::{traits::Fixed, types::I8F8};
+
+        assert_eq!(I8F8::min_max(&[]), None);
+        let one = I8F8::from_num(-1.5);
+        assert_eq!(I8F8::min_max(&[one]), Some((one, one)));
+        let vals = [3.5, -1.0, 0.0, -7.25, 2.0, 12.0, -0.5]
+            .iter()
+            .map(|&x| I8F8::from_num(x))
+            .collect::<std::vec::Vec<_>>();
+        for len in 1..=vals.len() {
+            let slice = &vals[..len];
+            let min = slice.iter().cloned().min().unwrap();
+            let max = slice.iter().cloned().max().unwrap();
+            assert_eq!(I8F8::min_max(slice), Some((min, max)));
+            let rev = slice.iter().rev().cloned().collect::<std::vec::Vec<_>>();
+            assert_eq!(I8F8::min_max(&rev), Some((min, max)));
+        }
+    }
+
+    #[test]
+    fn scale_codec() {
+        use crate::types::{I32F32, U0F8, U16F16};
+        use codec::{Decode, Encode, MaxEncodedLen};
+
+        let val = I32F32::from_num(1.5);
+        let encoded = val.encode();
+        assert_eq!(encoded, val.to_bits().encode());
+        assert_eq!(encoded, (3i64 << 31).to_le_bytes());
+        assert_eq!(I32F32::decode(&mut &encoded[..]), Ok(val));
+        let raw = (-5i64 << 30).encode();
+        assert_eq!(I32F32::decode(&mut &raw[..]), Ok(I32F32::from_num(-1.25)));
+        assert!(I32F32::decode(&mut &raw[..7]).is_err());
+
+        assert_eq!(I32F32::max_encoded_len(), 8);
+        assert_eq!(U16F16::max_encoded_len(), 4);
+        assert_eq!(U0F8::max_encoded_len(), 1);
+        assert_eq!(
+            U16F16::max_value().encode().len(),
+            U16F16::max_encoded_len()
+        );
+    }
+
+    #[test]
+    fn sqrt_unsigned() {
+        use crate::types::{U0F128, U0F32, U0F8, U128F0, U16F16, U64F64, U8F8};
+
+        fn check(bits: u64, frac_nbits: i32, root: f64) {
+            let exact = (bits as f64 / 2f64.powi(frac_nbits)).sqrt() * 2f64.powi(frac_nbits);
+            assert!((root - exact).abs() <= 0.5, "{} {} {}", bits, root, exact);
+        }
+
+        for i in 0..=0xffff_u16 {
+            let root = U8F8::from_bits(i).sqrt();
+            check(u64::from(i), 8, f64::from(root.to_bits()));
+        }
+        for i in 0..=0xff_u8 {
+            let root = U0F8::from_bits(i).sqrt();
+            check(u64::from(i), 8, f64::from(root.to_bits()));
+        }
+        for i in (0..=u32::MAX)
+            .step_by(9_999_991)
+            .chain(0..1000)
+            .chain(u32::MAX - 1000..=u32::MAX)
+        {
+            let root = U16F16::from_bits(i).sqrt();
+            check(u64::from(i), 16, f64::from(root.to_bits()));
+            let root = U0F32::from_bits(i).sqrt();
+            check(u64::from(i), 32, f64::from(root.to_bits()));
+        }
+
+        assert_eq!(U16F16::from_num(0).sqrt(), 0);
+        assert_eq!(U64F64::from_num(0.25).sqrt(), 0.5);
+        assert_eq!(U64F64::from_num(1u64 << 62).sqrt(), 1u64 << 31);
+        // √(2^128 − 1) is just under 2^64
+        assert_eq!(U128F0::max_value().sqrt(), 1u128 << 64);
+        let square = u128::from(u64::MAX) * u128::from(u64::MAX);
+        assert_eq!(U128F0::from_num(square).sqrt(), u64::MAX);
+        assert_eq!(
+            U128F0::from_num(square + u128::from(u64::MAX)).sqrt(),
+            u64::MAX
+        );
+        assert_eq!(
+            U128F0::from_num(square + u128::from(u64::MAX) + 1).sqrt(),
+            1u128 << 64
+        );
+        assert_eq!(U0F128::from_num(0.25).sqrt(), 0.5);
+        assert_eq!(U0F128::max_value().sqrt(), U0F128::max_value());
+    }
+
+    #[test]
+    fn sqrt_signed() {
+        use crate::types::{I0F16, I128F0, I16F16, I1F15, I8F8};
+
+        for i in 0..=i16::MAX {
+            let root = I8F8::from_bits(i).sqrt();
+            let unsigned = crate::types::U8F8::from_bits(i as u16).sqrt();
+            assert_eq!(root.to_bits() as u16, unsigned.to_bits());
+        }
+        let root: f64 = I16F16::from_num(2).sqrt().to_num();
+        assert!((root - 2f64.sqrt()).abs() <= 0.5 / 65536.0);
+        assert_eq!(I16F16::from_num(-1).checked_sqrt(), None);
+        assert_eq!(I16F16::from_bits(-1).checked_sqrt(), None);
+        assert_eq!(
+            I16F16::from_num(0).checked_sqrt(),
+            Some(I16F16::from_num(0))
+        );
+        assert_eq!(I128F0::from_bits(1 << 126).sqrt(), 1i128 << 63);
+
+        // √(1 − 2^−15) × 2^15 ≈ 2^15 − 0.5000076, which rounds to the maximum
+        assert_eq!(I1F15::max_value().checked_sqrt(), Some(I1F15::max_value()));
+        // with no integer bits, the root can be too large
+        assert_eq!(I0F16::from_num(0.25).checked_sqrt(), None);
+        assert_eq!(
+            I0F16::from_num(0.0625).checked_sqrt(),
+            Some(I0F16::from_num(0.25))
+        );
+    }
+
+    #[test]
+    fn recip() {
+        use crate::types::{I0F32, I16F16, I32F32, I64F64, U0F128, U16F16, U64F64};
+
+        for &f in &[0.5, 2.0, 3.0, -3.0, 0.1, -0.75, 1000.0, 1.0 / 1024.0] {
+            let a = I16F16::from_num(f).recip();
+            let exact = 1.0 / I16F16::from_num(f).to_num::<f64>();
+            assert!((a.to_num::<f64>() - exact).abs() < 1.0 / 65536.0);
+            let b = I64F64::from_num(f).recip();
+            assert!((b.to_num::<f64>() - 1.0 / I64F64::from_num(f).to_num::<f64>()).abs() < 1e-12);
+            if f > 0.0 {
+                let c = U64F64::from_num(f).recip();
+                assert_eq!(c.to_bits(), b.to_bits() as u128);
+            }
+        }
+        assert_eq!(I32F32::from_num(0.5).recip(), 2);
+        assert_eq!(
+            U16F16::from_num(0.5).checked_recip(),
+            Some(U16F16::from_num(2))
+        );
+        assert_eq!(U16F16::from_bits(1).checked_recip(), None);
+        assert_eq!(
+            U16F16::from_bits(2).checked_recip(),
+            Some(U16F16::from_bits(1 << 31))
+        );
+        assert_eq!(I16F16::from_bits(2).checked_recip(), None);
+        assert_eq!(
+            I16F16::from_bits(-2).checked_recip(),
+            Some(I16F16::min_value())
+        );
+        assert_eq!(I16F16::from_num(0).checked_recip(), None);
+        assert_eq!(I0F32::from_num(-0.5).checked_recip(), None);
+        assert_eq!(U0F128::from_num(0.5).checked_recip(), None);
+    }
+
+    #[test]
+    fn const_bits() {
+        use crate::types::{I16F16, U8F8};
+
+        const HALF: I16F16 = I16F16::from_bits(0x8000);
+        const VALUES: [I16F16; 4] = [
+            I16F16::from_bits(0),
+            HALF,
+            I16F16::from_bits(-0x1_8000),
+            I16F16::from_bits(HALF.to_bits() * 6),
+        ];
+        const LOW: u16 = U8F8::from_bits(0x1234).to_bits() & 0xff;
+
+        let bits: [i32; 4] = [0, 0x8000, -0x1_8000, 0x3_0000];
+        for (value, &bits) in VALUES.iter().zip(bits.iter()) {
+            assert_eq!(value.to_bits(), bits);
+        }
+        assert_eq!(HALF, 0.5);
+        assert_eq!(VALUES[2], -1.5);
+        assert_eq!(VALUES[3], 3);
+        assert_eq!(LOW, 0x34);
+    }
+
+    #[test]
+    fn min_max_delta_consts() {
+        use crate::types::{I0F16, I8F8, U0F16, U8F8};
+
+        const BOUNDS: [I8F8; 3] = [I8F8::MIN, I8F8::MAX, I8F8::DELTA];
+        assert_eq!(BOUNDS[0].to_bits(), i16::MIN);
+        assert_eq!(BOUNDS[1].to_bits(), i16::MAX);
+        assert_eq!(BOUNDS[2].to_bits(), 1);
+        assert_eq!(I8F8::MAX.to_bits(), i16::MAX);
+        assert_eq!(I8F8::MIN, I8F8::min_value());
+        assert_eq!(U8F8::MIN, 0);
+        assert_eq!(U8F8::MAX, U8F8::max_value());
+        assert_eq!(U0F16::DELTA, U0F16::from_bits(1));
+        assert_eq!(I0F16::DELTA, 1.0 / 65536.0);
+    }
+
+    #[test]
+    fn hash_map_keys() {
+        use crate::{
+            types::{extra::U8, I16F16},
+            FixedI16,
+        };
+        use std::collections::HashMap;
+
+        type Fix = FixedI16<U8>;
+        let mut map = HashMap::new();
+        for (i, &f) in [-1.5, -0.0, 0.0, 0.25, 3.0, -128.0].iter().enumerate() {
+            map.insert(Fix::from_num(f), i);
+        }
+        // -0.0 and 0.0 are the same fixed-point value, so 0 is a single key
+        assert_eq!(map.len(), 5);
+        assert_eq!(map[&Fix::from_num(0)], 2);
+        assert_eq!(map[&Fix::from_num(-1.5)], 0);
+        assert_eq!(map[&Fix::from_bits(0x40)], 3);
+        assert_eq!(map[&Fix::from_num(3)], 4);
+        assert_eq!(map[&Fix::min_value()], 5);
+        assert_eq!(map.get(&Fix::from_num(1)), None);
+
+        let mut map = HashMap::new();
+        map.insert(I16F16::from_num(1) / 3, "third");
+        assert_eq!(map[&(I16F16::from_num(2) / 6)], "third");
+    }
+
+    #[test]
+    fn bit_inspection() {
+        use crate::types::{I0F128, I16F16, I4F4, U0F8, U16F16, U64F64};
+
+        macro_rules! check {
+            ($($Fix:ident),*) => { $(
+                let nbits = core::mem::size_of::<$Fix>() as u32 * 8;
+                let ones = !$Fix::from_bits(0);
+                assert_eq!(ones.count_ones(), nbits);
+                assert_eq!(ones.count_zeros(), 0);
+                assert_eq!(ones.leading_zeros(), 0);
+                assert_eq!(ones.trailing_zeros(), 0);
+                let zero = $Fix::from_bits(0);
+                assert_eq!(zero.count_ones(), 0);
+                assert_eq!(zero.leading_zeros(), nbits);
+                assert_eq!(zero.trailing_zeros(), nbits);
+                let f = $Fix::from_bits(0b0110_1000);
+                assert_eq!(f.count_ones(), 3);
+                assert_eq!(f.leading_zeros(), nbits - 7);
+                assert_eq!(f.trailing_zeros(), 3);
+            )* };
+        }
+        check!(I4F4, U0F8, I16F16, U16F16, U64F64, I0F128);
+
+        // negative numbers are inspected in two's complement
+        let neg = I16F16::from_num(-1);
+        assert_eq!(neg.count_ones(), 16);
+        assert_eq!(neg.leading_zeros(), 0);
+        assert_eq!(neg.trailing_zeros(), 16);
+    }
+
+    #[test]
+    fn next_power_of_two() {
+        use crate::types::{U0F16, U16F16, U8F8};
+
+        assert_eq!(U8F8::from_num(3).next_power_of_two(), 4);
+        assert_eq!(U8F8::from_num(0.3).next_power_of_two(), 0.5);
+        assert_eq!(U8F8::from_num(4).next_power_of_two(), 4);
+        assert_eq!(U8F8::from_num(0.25).next_power_of_two(), 0.25);
+        assert_eq!(U8F8::from_num(0).next_power_of_two(), U8F8::DELTA);
+        assert_eq!(
+            U8F8::from_num(100).checked_next_power_of_two(),
+            Some(U8F8::from_num(128))
+        );
+        assert_eq!(U8F8::from_num(129).checked_next_power_of_two(), None);
+        assert_eq!(
+            U8F8::from_num(129).saturating_next_power_of_two(),
+            U8F8::MAX
+        );
+        assert_eq!(U8F8::from_num(129).wrapping_next_power_of_two(), 0);
+        assert_eq!(
+            U8F8::from_num(129).overflowing_next_power_of_two(),
+            (U8F8::from_num(0), true)
+        );
+        assert_eq!(
+            U16F16::from_num(1000).overflowing_next_power_of_two(),
+            (U16F16::from_num(1024), false)
+        );
+        assert_eq!(
+            U0F16::from_num(0.3).checked_next_power_of_two(),
+            Some(U0F16::from_num(0.5))
+        );
+        assert_eq!(U0F16::from_num(0.75).checked_next_power_of_two(), None);
+    }
+
+    #[test]
+    fn signum() {
+        use crate::types::{I0F32, I1F31, I32F0, I8F24};
+
+        for &(f, sign) in &[
+            (-3.5, -1),
+            (-0.125, -1),
+            (0.0, 0),
+            (1.0 / 1024.0, 1),
+            (100.0, 1),
+        ] {
+            let f = I8F24::from_num(f);
+            assert_eq!(f.signum(), sign);
+            assert_eq!(f.checked_signum(), Some(I8F24::from_num(sign)));
+            assert_eq!(f.saturating_signum(), sign);
+            assert_eq!(f.is_positive(), sign > 0);
+            assert_eq!(f.is_negative(), sign < 0);
+        }
+        assert_eq!(I32F0::min_value().signum(), -1);
+        assert_eq!(I32F0::max_value().signum(), 1);
+
+        // one integer bit holds −1 but not 1
+        assert_eq!(
+            I1F31::from_num(-0.5).checked_signum(),
+            Some(I1F31::from_num(-1))
+        );
+        assert_eq!(I1F31::from_num(0.5).checked_signum(), None);
+        assert_eq!(I1F31::from_num(0.5).saturating_signum(), I1F31::max_value());
+        // zero integer bits hold neither
+        assert_eq!(
+            I0F32::from_num(0).checked_signum(),
+            Some(I0F32::from_num(0))
+        );
+        assert_eq!(I0F32::from_num(0.25).checked_signum(), None);
+        assert_eq!(I0F32::from_num(-0.25).checked_signum(), None);
+        assert_eq!(
+            I0F32::from_num(0.25).saturating_signum(),
+            I0F32::max_value()
+        );
+        assert_eq!(
+            I0F32::from_num(-0.25).saturating_signum(),
+            I0F32::min_value()
+        );
+        assert!(I0F32::from_num(-0.25).is_negative());
+        assert!(!I0F32::from_num(0).is_positive());
+    }
+
+    #[test]
+    fn abs_family() {
+        use crate::types::{I0F8, I16F16, I64F64};
+
+        macro_rules! check {
+            ($($Fix:ident),*) => { $(
+                let (min, max, delta) = ($Fix::MIN, $Fix::MAX, $Fix::DELTA);
+                let zero = $Fix::from_bits(0);
+                assert_eq!((-delta).abs(), delta);
+                assert_eq!(zero.abs(), zero);
+                assert_eq!(max.abs(), max);
+                assert_eq!(min.checked_abs(), None);
+                assert_eq!((-delta).checked_abs(), Some(delta));
+                assert_eq!(zero.checked_abs(), Some(zero));
+                assert_eq!(max.checked_abs(), Some(max));
+                assert_eq!(min.saturating_abs(), max);
+                assert_eq!(min.wrapping_abs(), min);
+                assert_eq!((-delta).wrapping_abs(), delta);
+                assert_eq!(min.overflowing_abs(), (min, true));
+                assert_eq!((-delta).overflowing_abs(), (delta, false));
+                assert_eq!(zero.overflowing_abs(), (zero, false));
+                assert_eq!(max.overflowing_abs(), (max, false));
+            )* };
+        }
+        check!(I0F8, I16F16, I64F64);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn abs_min_panics() {
+        let _ = crate::types::I16F16::MIN.abs();
+    }
+
+    #[test]
+    fn abs_diff() {
+        use crate::types::{I0F128, I8F8, U0F128, U8F8};
+
+        assert_eq!(I8F8::MAX.abs_diff(I8F8::MIN), U8F8::MAX);
+        assert_eq!(I8F8::MIN.abs_diff(I8F8::MAX), U8F8::MAX);
+        assert_eq!(I0F128::MIN.abs_diff(I0F128::MAX), U0F128::MAX);
+        for &(a, b, diff) in &[(1.5, -2.25, 3.75), (-1.0, -3.5, 2.5), (0.0, 0.0, 0.0)] {
+            let (a, b) = (I8F8::from_num(a), I8F8::from_num(b));
+            assert_eq!(a.abs_diff(b), diff);
+            assert_eq!(b.abs_diff(a), diff);
+        }
+        assert_eq!(U8F8::MAX.abs_diff(U8F8::MIN), U8F8::MAX);
+        assert_eq!(U8F8::from_num(3).abs_diff(U8F8::from_num(7.5)), 4.5);
+        assert_eq!(U8F8::from_num(7.5).abs_diff(U8F8::from_num(3)), 4.5);
+    }
+
+    #[test]
+    fn neg_family() {
+        use crate::types::{I0F16, I16F16, U16F16, U8F8};
+
+        let min = I16F16::MIN;
+        assert_eq!(min.checked_neg(), None);
+        assert_eq!(min.saturating_neg(), I16F16::MAX);
+        assert_eq!(min.wrapping_neg(), min);
+        assert_eq!(min.overflowing_neg(), (min, true));
+        assert_eq!(I16F16::MAX.checked_neg(), Some(min + I16F16::DELTA));
+        let f = I16F16::from_num(2.5);
+        assert_eq!(-f, -2.5);
+        assert_eq!(f.checked_neg(), Some(-f));
+        assert_eq!(f.saturating_neg(), -f);
+        assert_eq!(f.wrapping_neg(), -f);
+        assert_eq!(f.overflowing_neg(), (-f, false));
+        assert_eq!(I0F16::MIN.overflowing_neg(), (I0F16::MIN, true));
+
+        let one = U16F16::from_num(1);
+        let zero = U16F16::from_num(0);
+        assert_eq!(one.checked_neg(), None);
+        assert_eq!(one.saturating_neg(), zero);
+        assert_eq!(one.wrapping_neg(), U16F16::from_num(65535));
+        assert_eq!(one.overflowing_neg(), (U16F16::from_num(65535), true));
+        assert_eq!(zero.checked_neg(), Some(zero));
+        assert_eq!(zero.saturating_neg(), zero);
+        assert_eq!(zero.wrapping_neg(), zero);
+        assert_eq!(zero.overflowing_neg(), (zero, false));
+        assert_eq!(U8F8::DELTA.wrapping_neg(), U8F8::MAX);
+    }
+
+    #[test]
+    fn rounding_against_f64() {
+        use crate::types::{I16F16, U16F16};
+
+        fn round_half_away(f: f64) -> f64 {
+            f.round()
+        }
+        fn round_ties_even(f: f64) -> f64 {
+            let r = f.round();
+            if (f - f.trunc()).abs() == 0.5 && r % 2.0 != 0.0 {
+                r - f.signum()
+            } else {
+                r
+            }
+        }
+
+        let values: [f64; 9] = [0.0, 0.25, 0.5, 1.5, 2.5, 2.75, 3.125, 100.5, 32766.25];
+        for &v in &values {
+            for &f in &[v, -v] {
+                let fix = I16F16::from_num(f);
+                assert_eq!(fix.floor(), f.floor(), "{}", f);
+                assert_eq!(fix.ceil(), f.ceil(), "{}", f);
+                assert_eq!(fix.round(), round_half_away(f), "{}", f);
+                assert_eq!(fix.round_ties_to_even(), round_ties_even(f), "{}", f);
+                assert_eq!(fix.round_to_zero(), f.trunc(), "{}", f);
+                assert_eq!(fix.int() + fix.frac(), fix);
+                assert_eq!(fix.frac(), f - f.floor(), "{}", f);
+            }
+            let fix = U16F16::from_num(v);
+            assert_eq!(fix.floor(), v.floor());
+            assert_eq!(fix.ceil(), v.ceil());
+            assert_eq!(fix.round(), round_half_away(v));
+            assert_eq!(fix.round_ties_to_even(), round_ties_even(v));
+            assert_eq!(fix.round_to_zero(), v.trunc());
+            assert_eq!(fix.frac(), v.fract());
+        }
+        assert_eq!(I16F16::MAX.checked_ceil(), None);
+        assert_eq!(I16F16::MAX.checked_round(), None);
+        assert_eq!(I16F16::MAX.checked_floor(), Some(I16F16::from_num(32767)));
+    }
+
+    #[test]
+    fn checked_rounding_bounds() {
+        use crate::types::{I8F8, U8F8};
+
+        let max = I8F8::MAX;
+        let near_max = I8F8::from_num(127.5);
+        assert_eq!(max.checked_ceil(), None);
+        assert_eq!(max.checked_round(), None);
+        assert_eq!(max.checked_floor(), Some(I8F8::from_num(127)));
+        assert_eq!(near_max.checked_round(), None);
+        assert_eq!(near_max.checked_ceil(), None);
+        assert_eq!(
+            (near_max - I8F8::DELTA).checked_round(),
+            Some(I8F8::from_num(127))
+        );
+        assert_eq!(
+            I8F8::from_num(127).checked_ceil(),
+            Some(I8F8::from_num(127))
+        );
+
+        let min = I8F8::MIN;
+        assert_eq!(min.checked_floor(), Some(min));
+        assert_eq!(min.checked_ceil(), Some(min));
+        assert_eq!((min + I8F8::DELTA).checked_floor(), Some(min));
+        assert_eq!(
+            I8F8::from_num(-2.5).checked_round(),
+            Some(I8F8::from_num(-3))
+        );
+        assert_eq!(I8F8::from_num(2.25).checked_ceil(), Some(I8F8::from_num(3)));
+        assert_eq!(
+            I8F8::from_num(-2.25).checked_floor(),
+            Some(I8F8::from_num(-3))
+        );
+
+        assert_eq!(U8F8::MAX.checked_ceil(), None);
+        assert_eq!(U8F8::MAX.checked_round(), None);
+        assert_eq!(U8F8::MAX.checked_floor(), Some(U8F8::from_num(255)));
+        assert_eq!(
+            U8F8::from_num(254.5).checked_round(),
+            Some(U8F8::from_num(255))
+        );
+    }
+
+    #[test]
+    fn to_float_exact() {
+        use crate::types::{I16F16, I32F32, I64F64, U0F128, U32F0};
+
+        assert_eq!(I16F16::from_num(0.5).to_f64_exact(), Ok(0.5));
+        assert_eq!(I16F16::from_num(-0.5).to_f32_exact(), Ok(-0.5));
+        assert_eq!(
+            I16F16::max_value().to_f64_exact(),
+            Ok(32768.0 - 1.0 / 65536.0)
+        );
+        // 32 significant bits are more than the 24 of f32
+        let error = I16F16::max_value().to_f32_exact().unwrap_err();
+        assert_eq!(error.rounded(), 32768.0);
+        assert_eq!(U32F0::from_num(0xFF_FFFF).to_f32_exact(), Ok(16_777_215.0));
+        assert!(U32F0::from_num(0x1FF_FFFF).to_f32_exact().is_err());
+        // 54 significant bits are more than the 53 of f64
+        let f = I32F32::from_bits((1 << 53) + 1);
+        assert_eq!(f.to_f64_exact().unwrap_err().rounded(), f.to_num::<f64>());
+        assert!(I32F32::from_bits(1 << 53).to_f64_exact().is_ok());
+        assert_eq!(
+            I64F64::min_value().to_f64_exact(),
+            Ok(-9_223_372_036_854_775_808.0)
+        );
+        assert!(U0F128::max_value().to_f64_exact().is_err());
+    }
+
+    #[test]
+    fn byte_conversions() {
+        use crate::types::{I32F32, U8F8};
+
+        const BYTES: [u8; 2] = U8F8::from_bits(0x1234).to_be_bytes();
+        const FROM_LE: U8F8 = U8F8::from_le_bytes([0x34, 0x12]);
+        assert_eq!(BYTES, [0x12, 0x34]);
+        assert_eq!(FROM_LE.to_bits(), 0x1234);
+
+        for &f in &[0.0f64, 1.5, 200.75, 255.996_093_75] {
+            let a = U8F8::from_num(f);
+            let mut le = a.to_le_bytes();
+            assert_eq!(U8F8::from_le_bytes(le), a);
+            assert_eq!(U8F8::from_be_bytes(a.to_be_bytes()), a);
+            assert_eq!(U8F8::from_ne_bytes(a.to_ne_bytes()), a);
+            le.reverse();
+            assert_eq!(le, a.to_be_bytes());
+        }
+        for &f in &[0.0f64, -1.5, 1e9 + 0.25, -2_147_483_648.0] {
+            let a = I32F32::from_num(f);
+            let mut le = a.to_le_bytes();
+            assert_eq!(I32F32::from_le_bytes(le), a);
+            assert_eq!(I32F32::from_be_bytes(a.to_be_bytes()), a);
+            assert_eq!(I32F32::from_ne_bytes(a.to_ne_bytes()), a);
+            le.reverse();
+            assert_eq!(le, a.to_be_bytes());
+        }
+        assert_eq!(
+            I32F32::from_num(-1.5).to_be_bytes(),
+            [0xFF, 0xFF, 0xFF, 0xFE, 0x80, 0x00, 0x00, 0x00]
+        );
+    }
+
+    #[test]
+    fn to_num_round() {
+        use crate::types::{I16F16, U0F8, U4F4, U8F8};
+        use core::cmp::Ordering;
+
+        assert_eq!(
+            I16F16::from_num(2.5).to_num_round::<i32>(),
+            (2, Ordering::Greater)
+        );
+        assert_eq!(
+            I16F16::from_num(-2.5).to_num_round::<i32>(),
+            (-3, Ordering::Greater)
+        );
+        assert_eq!(
+            I16F16::from_num(-7).to_num_round::<i8>(),
+            (-7, Ordering::Equal)
+        );
+        assert_eq!(
+            I16F16::from_num(7).to_num_round::<u128>(),
+            (7, Ordering::Equal)
+        );
+        assert_eq!(
+            U8F8::from_num(0.75).to_num_round::<u8>(),
+            (0, Ordering::Greater)
+        );
+        assert_eq!(
+            U0F8::from_num(0.5).to_num_round::<f32>(),
+            (0.5, Ordering::Equal)
+        );
+        // 32 significant bits do not fit in f32, so the result rounds up
+        let (rounded, ord) = I16F16::max_value().to_num_round::<f32>();
+        assert_eq!((rounded, ord), (32768.0, Ordering::Less));
+        assert_eq!(
+            I16F16::from_num(1.75).to_num_round::<U8F8>(),
+            (U8F8::from_num(1.75), Ordering::Equal)
+        );
+        assert_eq!(
+            U8F8::from_bits(0x0181).to_num_round::<U4F4>(),
+            (U4F4::from_num(1.5), Ordering::Greater)
+        );
+    }
+
+    #[test]
+    fn clamp_min_max() {
+        use crate::types::{I16F16, U8F8};
+        let (lo, hi) = (I16F16::from_num(-1.5), I16F16::from_num(2.25));
+        assert_eq!(I16F16::from_num(-3).clamp(lo, hi), lo);
+        assert_eq!(I16F16::min_value().clamp(lo, hi), lo);
+        assert_eq!(I16F16::from_num(-1.5).clamp(lo, hi), lo);
+        assert_eq!(I16F16::from_num(0.5).clamp(lo, hi), I16F16::from_num(0.5));
+        assert_eq!(I16F16::from_num(2.25).clamp(lo, hi), hi);
+        assert_eq!(I16F16::max_value().clamp(lo, hi), hi);
+        assert_eq!(lo.clamp(lo, lo), lo);
+        assert_eq!(lo.min(hi), lo);
+        assert_eq!(hi.min(lo), lo);
+        assert_eq!(lo.max(hi), hi);
+        assert_eq!(hi.max(lo), hi);
+
+        let (lo, hi) = (U8F8::from_num(1), U8F8::from_num(200));
+        assert_eq!(U8F8::from_num(0).clamp(lo, hi), lo);
+        assert_eq!(U8F8::from_num(100.5).clamp(lo, hi), U8F8::from_num(100.5));
+        assert_eq!(U8F8::max_value().clamp(lo, hi), hi);
+        assert_eq!(U8F8::max_value().min(hi), hi);
+        assert_eq!(U8F8::min_value().max(lo), lo);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "min > max")]
+    fn clamp_min_greater_than_max() {
+        use crate::types::I16F16;
+        let _ = I16F16::from_num(0).clamp(I16F16::from_num(1), I16F16::from_num(-1));
+    }
+
+    #[test]
+    fn euclid_negative_dividend() {
+        use crate::types::{I16F16, I8F8};
+        let tau = I16F16::from_num(6.25);
+        for &angle in &[-20.5, -12.5, -6.25, -6.0, -0.125, -0.0] {
+            let angle = I16F16::from_num(angle);
+            let rem = angle.rem_euclid(tau);
+            assert!(!rem.is_negative() && rem < tau);
+            let quot = angle.div_euclid(tau);
+            assert_eq!(quot.frac(), 0);
+            assert_eq!(quot * tau + rem, angle);
+            assert_eq!(angle.checked_rem_euclid(tau), Some(rem));
+            assert_eq!(angle.checked_div_euclid(tau), Some(quot));
+            assert_eq!(angle.overflowing_div_euclid(tau), (quot, false));
+            // a negative modulus gives the same non-negative remainder
+            assert_eq!(angle.rem_euclid(-tau), rem);
+            assert_eq!(angle.div_euclid(-tau), -quot);
+        }
+        let angle = I16F16::from_num(-20.5);
+        assert_eq!(angle.rem_euclid(tau), I16F16::from_num(4.5));
+        assert_eq!(angle.div_euclid(tau), -4);
+        assert_eq!((angle % tau), I16F16::from_num(-1.75));
+        assert_eq!(angle.rem_euclid_int(3), I16F16::from_num(0.5));
+        assert_eq!(angle.div_euclid_int(3), -7);
+        assert_eq!(angle.checked_rem_euclid(I16F16::from_num(0)), None);
+        assert_eq!(angle.checked_div_euclid(I16F16::from_num(0)), None);
+
+        // the remainder cannot overflow, even with the smallest divisor
+        let delta = I8F8::from_bits(-1);
+        assert_eq!(I8F8::min_value().rem_euclid(delta), 0);
+        assert_eq!(
+            I8F8::min_value().checked_rem_euclid(delta),
+            Some(I8F8::from_num(0))
+        );
+        let (_, overflow) = I8F8::min_value().overflowing_div_euclid(delta);
+        assert!(overflow);
+    }
+
+    #[test]
+    fn rem_op() {
+        use crate::types::{I16F16, I8F8, U8F8};
+        let two = I16F16::from_num(2);
+        assert_eq!(I16F16::from_num(5.5) % two, I16F16::from_num(1.5));
+        assert_eq!(I16F16::from_num(5.5) % -two, I16F16::from_num(1.5));
+        // the remainder has the sign of the dividend, like integer %
+        assert_eq!(I16F16::from_num(-5.5) % two, I16F16::from_num(-1.5));
+        assert_eq!(I16F16::from_num(-5.5) % -two, I16F16::from_num(-1.5));
+        assert_eq!(I16F16::from_num(-4) % two, 0);
+        let mut a = I16F16::from_num(-7.25);
+        a %= two;
+        assert_eq!(a, I16F16::from_num(-1.25));
+        assert_eq!(I16F16::from_num(-5.5) % 2, I16F16::from_num(-1.5));
+        assert_eq!(
+            U8F8::from_num(5.5) % U8F8::from_num(0.75),
+            U8F8::from_num(0.25)
+        );
+
+        assert_eq!(
+            I16F16::from_num(5.5).checked_rem(two),
+            Some(I16F16::from_num(1.5))
+        );
+        assert_eq!(I16F16::from_num(5.5).checked_rem(I16F16::from_num(0)), None);
+        let delta = I8F8::from_bits(-1);
+        assert_eq!(I8F8::min_value() % delta, 0);
+        assert_eq!(
+            I8F8::min_value().checked_rem(delta),
+            Some(I8F8::from_num(0))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "division by zero")]
+    fn rem_by_zero() {
+        use crate::types::I16F16;
+        let _ = I16F16::from_num(5.5) % I16F16::from_num(0);
+    }
+
+    #[test]
+    fn shift_ops() {
+        use crate::types::{I16F16, U8F8};
+        let one = I16F16::from_num(1);
+        assert_eq!(one << 2, 4);
+        assert_eq!(one << 2u8, 4);
+        assert_eq!(I16F16::from_num(-1.5) << 3, -12);
+        assert_eq!(I16F16::from_num(3) >> 1, I16F16::from_num(1.5));
+        assert_eq!(I16F16::from_num(-3) >> 1, I16F16::from_num(-1.5));
+        let mut a = I16F16::from_num(0.75);
+        a <<= 4;
+        assert_eq!(a, 12);
+        a >>= 5i32;
+        assert_eq!(a, I16F16::from_num(0.375));
+
+        // bits shifted out are lost, rounding towards negative infinity
+        let small = I16F16::from_bits(3);
+        assert_eq!(small >> 1, I16F16::from_bits(1));
+        assert_eq!(-small >> 1, I16F16::from_bits(-2));
+        assert_eq!(I16F16::from_bits(-1) >> 10, I16F16::from_bits(-1));
+        assert_eq!(U8F8::from_bits(1) >> 1, 0);
+
+        assert_eq!(one.checked_shl(15), Some(I16F16::from_bits(i32::MIN)));
+        assert_eq!(one.checked_shl(31), Some(I16F16::from_num(0)));
+        assert_eq!(one.checked_shl(32), None);
+        assert_eq!(one.checked_shr(31), Some(I16F16::from_num(0)));
+        assert_eq!(one.checked_shr(32), None);
+        assert_eq!(one.wrapping_shl(34), 4);
+        assert_eq!(one.overflowing_shr(33), (I16F16::from_num(0.5), true));
+    }
+
+    #[test]
+    fn bitwise_ops() {
+        use crate::types::{I8F8, U8F8};
+        let a = I8F8::from_bits(0xFF00u16 as i16);
+        let b = I8F8::from_bits(0x0FF0);
+        assert_eq!(a & b, I8F8::from_bits(0x0F00));
+        assert_eq!(a | b, I8F8::from_bits(0xFFF0u16 as i16));
+        assert_eq!(a ^ b, I8F8::from_bits(0xF0F0u16 as i16));
+        assert_eq!(!b, I8F8::from_bits(0xF00Fu16 as i16));
+        // the operators act on the bits, not on the value: -1 & 0.5 is 0
+        assert_eq!(a, -1);
+        assert_eq!(a & I8F8::from_num(0.5), 0);
+        assert_eq!(!I8F8::from_num(0), I8F8::from_bits(-1));
+        assert_eq!(!I8F8::from_num(0), -I8F8::from_bits(1));
+
+        let mut c = U8F8::from_bits(0xFF00);
+        c &= U8F8::from_bits(0x0FF0);
+        assert_eq!(c, U8F8::from_bits(0x0F00));
+        c |= U8F8::from_bits(0x000F);
+        assert_eq!(c, U8F8::from_bits(0x0F0F));
+        c ^= U8F8::from_bits(0xFFFF);
+        assert_eq!(c, U8F8::from_bits(0xF0F0));
+        assert_eq!(!c, U8F8::from_bits(0x0F0F));
+    }
+
+    #[test]
+    fn generic_bits_round_trip() {
+        use crate::{
+            traits::Fixed,
+            types::{I0F8, I16F16, I64F64, U0F128, U8F8},
+        };
+        fn check<F: Fixed>(vals: &[F]) {
+            for &x in vals {
+                let bits = x.to_bits();
+                assert_eq!(F::from_bits(bits), x);
+                assert_eq!(F::from_bits(bits).to_bits(), bits);
+                assert_eq!(
+                    bits.cmp(&F::from_bits(bits).to_bits()),
+                    core::cmp::Ordering::Equal
+                );
+            }
+            assert_eq!(F::from_bits(F::Bits::default()), F::default());
+            assert!(F::min_value().to_bits() <= F::max_value().to_bits());
+        }
+        check(&[I0F8::min_value(), I0F8::from_num(0.25), I0F8::max_value()]);
+        check(&[
+            I16F16::from_num(-2.75),
+            I16F16::from_num(0),
+            I16F16::max_value(),
+        ]);
+        check(&[I64F64::min_value(), I64F64::from_num(1.5e10)]);
+        check(&[U8F8::from_num(200.5), U8F8::max_value()]);
+        check(&[U0F128::from_num(0.5), U0F128::max_value()]);
+    }
+
+    #[test]
+    fn generic_nbits() {
+        use crate::{
+            traits::Fixed,
+            types::{I16F16, I8F120, U0F8},
+        };
+        fn layout<F: Fixed>() -> (u32, u32, u32) {
+            assert_eq!(F::INT_NBITS, F::int_nbits());
+            assert_eq!(F::FRAC_NBITS, F::frac_nbits());
+            assert_eq!(F::INT_NBITS + F::FRAC_NBITS, F::NBITS);
+            (F::INT_NBITS, F::FRAC_NBITS, F::NBITS)
+        }
+        assert_eq!(layout::<I16F16>(), (16, 16, 32));
+        assert_eq!(layout::<U0F8>(), (0, 8, 8));
+        assert_eq!(layout::<I8F120>(), (8, 120, 128));
+        assert_eq!(<I16F16 as Fixed>::INT_NBITS, I16F16::INT_NBITS);
+        assert_eq!(<U0F8 as Fixed>::FRAC_NBITS, U0F8::FRAC_NBITS);
+    }
+
+    #[test]
+    fn int_part_frac_part() {
+        use crate::types::{I0F8, I16F16, I1F7, U0F8, U8F8};
+        let a = I16F16::from_num(-2.25);
+        assert_eq!(a.int_part(), -2);
+        assert_eq!(a.frac_part(), -0.25);
+        assert_eq!(a.int_part() + a.frac_part(), a);
+        assert_eq!(a.int(), -3);
+        assert_eq!(a.frac(), 0.75);
+        let b = I16F16::from_num(2.25);
+        assert_eq!(b.int_part(), 2);
+        assert_eq!(b.frac_part(), 0.25);
+        assert_eq!(I16F16::from_num(-4).int_part(), -4);
+        assert_eq!(I16F16::from_num(-4).frac_part(), 0);
+        let min = I16F16::min_value();
+        assert_eq!(min.int_part(), min);
+        assert_eq!(min.frac_part(), 0);
+        let min_plus = min + I16F16::from_bits(1);
+        assert_eq!(min_plus.int_part(), min + I16F16::from_num(1));
+        assert_eq!(min_plus.int_part() + min_plus.frac_part(), min_plus);
+
+        // one integer bit, which has the value -1
+        let c = I1F7::from_num(-0.25);
+        assert_eq!(c.int_part(), 0);
+        assert_eq!(c.frac_part(), c);
+        assert_eq!(I1F7::from_num(-1).int_part(), -1);
+        assert_eq!(I1F7::from_num(-1).frac_part(), 0);
+
+        // no integer bits
+        for &x in &[I0F8::min_value(), I0F8::from_num(-0.125), I0F8::max_value()] {
+            assert_eq!(x.int_part(), 0);
+            assert_eq!(x.frac_part(), x);
+        }
+        assert_eq!(U0F8::max_value().frac_part(), U0F8::max_value());
+        assert_eq!(U8F8::from_num(7.75).int_part(), 7);
+        assert_eq!(U8F8::from_num(7.75).frac_part(), 0.75);
+    }
+
+    #[test]
+    fn mul_int_family() {
+        use crate::{
+            traits::Fixed,
+            types::{I16F16, U8F8},
+        };
+        assert_eq!(U8F8::from_num(0.5).mul_int(3), 1.5);
+        assert_eq!(U8F8::from_num(0.5).mul_int(3), U8F8::from_num(0.5) * 3);
+        assert_eq!(<U8F8 as Fixed>::mul_int(U8F8::from_num(2.25), 4), 9);
+        assert_eq!(I16F16::from_num(-2.125).mul_int(-8), 17);
+
+        // 128 × 2 = 256 does not fit in U8F8
+        let big = U8F8::from_num(128);
+        assert_eq!(big.checked_mul_int(2), None);
+        assert_eq!(big.checked_mul_int(1), Some(big));
+        assert_eq!(big.saturating_mul_int(2), U8F8::max_value());
+        assert_eq!(big.wrapping_mul_int(2), 0);
+        assert_eq!(big.overflowing_mul_int(2), (U8F8::from_num(0), true));
+        assert_eq!(big.overflowing_mul_int(0), (U8F8::from_num(0), false));
+        let min = I16F16::min_value();
+        assert_eq!(min.checked_mul_int(-1), None);
+        assert_eq!(min.saturating_mul_int(-1), I16F16::max_value());
+        assert_eq!(min.wrapping_mul_int(-1), min);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "overflow")]
+    fn mul_int_overflow() {
+        use crate::types::U8F8;
+        let _ = U8F8::from_num(128).mul_int(2);
+    }
+
+    #[test]
+    fn div_int_round() {
+        use crate::types::{I16F16, I4F4, U4F4};
+        assert_eq!(I16F16::from_num(7).div_int(2), 3.5);
+        assert_eq!(I16F16::from_num(7).div_int(2), I16F16::from_num(7) / 2);
+        assert_eq!(I16F16::from_num(7).div_int_round(2), 3.5);
+
+        // compare with rounding computed in a wider type
+        fn round_away(a: i32, b: i32) -> i32 {
+            let (q, r) = (a / b, a % b);
+            if 2 * r.abs() >= b.abs() {
+                q + (a.signum() * b.signum())
+            } else {
+                q
+            }
+        }
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                if b == 0 || (a == i8::MIN && b == -1) {
+                    continue;
+                }
+                let fa = I4F4::from_bits(a);
+                let expected = round_away(i32::from(a), i32::from(b));
+                assert_eq!(i32::from(fa.div_int_round(b).to_bits()), expected);
+                assert_eq!(fa.div_int(b).to_bits(), a / b);
+            }
+        }
+        for a in 0..=u8::MAX {
+            for b in 1..=u8::MAX {
+                let fa = U4F4::from_bits(a);
+                let expected = round_away(i32::from(a), i32::from(b));
+                assert_eq!(i32::from(fa.div_int_round(b).to_bits()), expected);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "divide by zero")]
+    fn div_int_round_by_zero() {
+        use crate::types::I16F16;
+        let _ = I16F16::from_num(7).div_int_round(0);
+    }
+
+    #[test]
+    fn div_rem() {
+        use crate::types::{I16F16, I4F4, U8F8};
+        let (q, r) = I16F16::from_num(7).div_rem(I16F16::from_num(2));
+        assert_eq!(q, 3);
+        assert_eq!(r, 1);
+        let (q, r) = I16F16::from_num(-7).div_rem(I16F16::from_num(2));
+        assert_eq!((q, r), (I16F16::from_num(-3), I16F16::from_num(-1)));
+        let (q, r) = I16F16::from_num(7).div_rem(I16F16::from_num(-2.5));
+        assert_eq!((q, r), (I16F16::from_num(-2), I16F16::from_num(2)));
+        let (q, r) = U8F8::from_num(100.25).div_rem(U8F8::from_num(0.75));
+        assert_eq!((q, r), (U8F8::from_num(133), U8F8::from_num(0.5)));
+
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                if b == 0 {
+                    continue;
+                }
+                let quot = i32::from(a) / i32::from(b);
+                if !(-8..=7).contains(&quot) {
+                    continue;
+                }
+                let (q, r) = I4F4::from_bits(a).div_rem(I4F4::from_bits(b));
+                assert_eq!(q.to_num::<i32>(), quot);
+                assert_eq!(r, I4F4::from_bits(a) % I4F4::from_bits(b));
+                // q * b + r == a, computed exactly in i32
+                let prod_bits = quot * i32::from(b);
+                assert_eq!(prod_bits + i32::from(r.to_bits()), i32::from(a));
+            }
+        }
+    }
+
+    #[test]
+    fn div_rem_no_int_bits() {
+        use crate::types::{I0F8, U0F8};
+        let (q, r) = U0F8::from_num(0.25).div_rem(U0F8::from_num(0.5));
+        assert_eq!((q, r), (U0F8::from_num(0), U0F8::from_num(0.25)));
+        let (q, r) = I0F8::from_num(-0.25).div_rem(I0F8::from_num(0.375));
+        assert_eq!((q, r), (I0F8::from_num(0), I0F8::from_num(-0.25)));
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic(expected = "overflow"))]
+    fn div_rem_no_int_bits_overflow() {
+        use crate::types::U0F8;
+        let half = U0F8::from_num(0.5);
+        // the quotient 1 wraps to 0
+        assert_eq!(half.div_rem(half), (U0F8::from_num(0), U0F8::from_num(0)));
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic(expected = "overflow"))]
+    fn div_rem_min_by_neg_delta() {
+        use crate::types::I8F8;
+        let (q, r) = I8F8::min_value().div_rem(I8F8::from_bits(-1));
+        // the quotient 2^15 wraps to 0
+        assert_eq!((q, r), (I8F8::from_num(0), I8F8::from_num(0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "divide by zero")]
+    fn div_rem_by_zero() {
+        use crate::types::I16F16;
+        let _ = I16F16::from_num(7).div_rem(I16F16::from_num(0));
+    }
+
+    #[test]
+    fn from_num_rounding_modes() {
+        use crate::types::{I16F16, I8F8};
+        // expected bits for floor, ceil, trunc and round_ties_even
+        fn check<Src: crate::traits::ToFixed + Copy>(src: Src, bits: [i16; 4]) {
+            assert_eq!(I8F8::from_num_floor(src).to_bits(), bits[0]);
+            assert_eq!(I8F8::from_num_ceil(src).to_bits(), bits[1]);
+            assert_eq!(I8F8::from_num_trunc(src).to_bits(), bits[2]);
+            assert_eq!(I8F8::from_num_round_ties_even(src).to_bits(), bits[3]);
+        }
+        // (source bits in units of 1/4 of an I8F8 delta, expected bits)
+        let cases: [(i32, [i16; 4]); 14] = [
+            (256 * 4, [256, 256, 256, 256]),
+            (256 * 4 + 1, [256, 257, 256, 256]),
+            (256 * 4 + 2, [256, 257, 256, 256]),
+            (256 * 4 + 3, [256, 257, 256, 257]),
+            (257 * 4 + 2, [257, 258, 257, 258]),
+            (-256 * 4 - 1, [-257, -256, -256, -256]),
+            (-256 * 4 - 2, [-257, -256, -256, -256]),
+            (-256 * 4 - 3, [-257, -256, -256, -257]),
+            (-257 * 4 - 2, [-258, -257, -257, -258]),
+            (1, [0, 1, 0, 0]),
+            (-1, [-1, 0, 0, 0]),
+            (-2, [-1, 0, 0, 0]),
+            (i32::from(i16::MAX) * 4 + 1, [i16::MAX; 4]),
+            (i32::from(i16::MIN) * 4 - 1, [i16::MIN; 4]),
+        ];
+        for &(quarters, bits) in &cases {
+            let overflows_up = quarters > i32::from(i16::MAX) * 4;
+            let overflows_down = quarters < i32::from(i16::MIN) * 4;
+            if overflows_up || overflows_down {
+                // only test the modes that do not overflow
+                let f = f64::from(quarters) / 1024.0;
+                let fixed = I16F16::from_bits(quarters << 6);
+                if overflows_up {
+                    assert_eq!(I8F8::from_num_floor(f).to_bits(), bits[0]);
+                    assert_eq!(I8F8::from_num_floor(fixed).to_bits(), bits[0]);
+                } else {
+                    assert_eq!(I8F8::from_num_ceil(f).to_bits(), bits[1]);
+                    assert_eq!(I8F8::from_num_ceil(fixed).to_bits(), bits[1]);
+                }
+                assert_eq!(I8F8::from_num_trunc(f).to_bits(), bits[2]);
+                assert_eq!(I8F8::from_num_trunc(fixed).to_bits(), bits[2]);
+                assert_eq!(I8F8::from_num_round_ties_even(f).to_bits(), bits[3]);
+                assert_eq!(I8F8::from_num_round_ties_even(fixed).to_bits(), bits[3]);
+                continue;
+            }
+            check(f64::from(quarters) / 1024.0, bits);
+            check(f32::from(quarters as i16) / 1024.0, bits);
+            check(I16F16::from_bits(quarters << 6), bits);
+        }
+        // from_num is unchanged: nearest for floats, truncation for fixed
+        assert_eq!(I8F8::from_num(1.0f64 + 3.0 / 1024.0).to_bits(), 257);
+        assert_eq!(
+            I8F8::from_num(I16F16::from_bits((256 << 8) + 192)).to_bits(),
+            256
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn from_num_ceil_overflow() {
+        use crate::types::{I16F16, I8F8};
+        let _ = I8F8::from_num_ceil(I16F16::from_bits((i32::from(i16::MAX) << 8) + 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn from_num_floor_overflow() {
+        use crate::types::I8F8;
+        let _ = I8F8::from_num_floor(-128.001f64);
+    }
+
+    #[test]
+    fn to_num_rounding_modes() {
+        use crate::types::{I16F16, I8F8};
+        let x = I16F16::from_num(-1.5);
+        assert_eq!(x.to_num::<i32>(), -2);
+        assert_eq!(x.to_num_floor::<i32>(), -2);
+        assert_eq!(x.to_num_ceil::<i32>(), -1);
+        assert_eq!(x.to_num_trunc::<i32>(), -1);
+        let y = I16F16::from_num(1.5);
+        assert_eq!(y.to_num_floor::<u8>(), 1);
+        assert_eq!(y.to_num_ceil::<u8>(), 2);
+        assert_eq!(y.to_num_trunc::<u8>(), 1);
+        assert_eq!(I16F16::from_num(3).to_num_ceil::<i64>(), 3);
+
+        // narrower fixed-point destination: 1/512 is half an I8F8 delta
+        let z = I16F16::from_num(-1) - I16F16::from_bits(1 << 7);
+        assert_eq!(z.to_num_floor::<I8F8>().to_bits(), -257);
+        assert_eq!(z.to_num_ceil::<I8F8>().to_bits(), -256);
+        assert_eq!(z.to_num_trunc::<I8F8>().to_bits(), -256);
+
+        // float destinations are unaffected
+        assert_eq!(x.to_num_floor::<f64>(), -1.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn to_num_ceil_overflow() {
+        use crate::types::I16F16;
+        let _ = I16F16::from_num(255.5).to_num_ceil::<u8>();
+    }
+
+    #[test]
+    fn from_num_checked_detail() {
+        use crate::{
+            types::{I8F8, U4F4, U8F0},
+            FromNumError,
+        };
+        assert_eq!(
+            U8F0::from_num_checked_detail(-5i32),
+            Err(FromNumError::Underflow)
+        );
+        assert_eq!(
+            U8F0::from_num_checked_detail(256u32),
+            Err(FromNumError::Overflow)
+        );
+        assert_eq!(
+            U8F0::from_num_checked_detail(255u32),
+            Ok(U8F0::from_num(255))
+        );
+        assert_eq!(
+            U4F4::from_num_checked_detail(16u32),
+            Err(FromNumError::Overflow)
+        );
+        assert_eq!(U4F4::from_num_checked_detail(15u32), Ok(U4F4::from_num(15)));
+        assert_eq!(
+            I8F8::from_num_checked_detail(-129),
+            Err(FromNumError::Underflow)
+        );
+        assert_eq!(I8F8::from_num_checked_detail(-128), Ok(I8F8::min_value()));
+        assert_eq!(
+            I8F8::from_num_checked_detail(1e3),
+            Err(FromNumError::Overflow)
+        );
+        assert_eq!(
+            I8F8::from_num_checked_detail(f32::NEG_INFINITY),
+            Err(FromNumError::Underflow)
+        );
+    }
+
+    #[test]
+    fn default_is_zero() {
+        use crate::types::{I16F16, U0F8, U8F8};
+        #[derive(Default)]
+        struct Gains {
+            proportional: I16F16,
+            integral: U8F8,
+            scale: U0F8,
+        }
+        assert_eq!(I16F16::default().to_bits(), 0);
+        let gains = Gains::default();
+        assert_eq!(gains.proportional, 0);
+        assert_eq!(gains.integral, 0);
+        assert_eq!(gains.scale.to_bits(), 0);
+    }
+
+    #[test]
+    fn is_power_of_two() {
+        use crate::types::{U0F8, U16F16};
+        assert!(U16F16::from_num(4).is_power_of_two());
+        assert!(U16F16::from_num(0.25).is_power_of_two());
+        assert!(U16F16::from_num(1).is_power_of_two());
+        assert!(U16F16::from_bits(1).is_power_of_two());
+        assert!(!U16F16::from_num(3).is_power_of_two());
+        assert!(!U16F16::from_num(0).is_power_of_two());
+        assert!(!U16F16::max_value().is_power_of_two());
+        assert!(U0F8::from_num(0.5).is_power_of_two());
+    }
+
+    #[test]
+    fn rotate() {
+        use crate::types::{I8F8, U16F16};
+        assert_eq!(I8F8::from_bits(0x0001).rotate_left(4).to_bits(), 0x0010);
+        assert_eq!(I8F8::from_bits(0x0001).rotate_right(4).to_bits(), 0x1000);
+        let x = I8F8::from_num(-3.75);
+        assert_eq!(x.rotate_left(16), x);
+        assert_eq!(x.rotate_right(16), x);
+        assert_eq!(x.rotate_left(5).rotate_right(5), x);
+        let y = U16F16::from_bits(0x8000_0001);
+        assert_eq!(y.rotate_left(1).to_bits(), 0x0000_0003);
+        assert_eq!(y.rotate_left(32), y);
+    }
+
+    #[test]
+    fn swap_bytes_reverse_bits() {
+        use crate::types::{I16F16, U8F8};
+        let x = I16F16::from_bits(0x1234_5678);
+        assert_eq!(x.swap_bytes().to_bits(), 0x7856_3412);
+        assert_eq!(x.swap_bytes(), I16F16::from_be_bytes(x.to_le_bytes()));
+        assert_eq!(x.swap_bytes().swap_bytes(), x);
+        assert_eq!(
+            U8F8::from_bits(0b0000_0001_0110_0000)
+                .reverse_bits()
+                .to_bits(),
+            0b0000_0110_1000_0000
+        );
+        assert_eq!(I16F16::from_bits(1).reverse_bits(), I16F16::min_value());
+        assert_eq!(x.reverse_bits().reverse_bits(), x);
+    }
+
+    #[test]
+    fn from_fixed_family() {
+        use crate::{
+            traits::FromFixed,
+            types::extra::{U4, U8},
+            FixedI16, FixedI32, FixedI8,
+        };
+        let small = FixedI16::<U8>::from_num(3.5);
+        let big = FixedI16::<U8>::from_num(100);
+        assert_eq!(
+            FixedI8::<U4>::checked_from_fixed(small),
+            Some(FixedI8::<U4>::from_num(3.5))
+        );
+        assert_eq!(FixedI8::<U4>::checked_from_fixed(big), None);
+        assert_eq!(
+            FixedI8::<U4>::saturating_from_fixed(big),
+            FixedI8::<U4>::max_value()
+        );
+        assert_eq!(
+            FixedI8::<U4>::saturating_from_fixed(-big),
+            FixedI8::<U4>::min_value()
+        );
+        // 100 = 0x64, wrapped to 4 integer bits is 0x4
+        assert_eq!(
+            FixedI8::<U4>::wrapping_from_fixed(big),
+            FixedI8::<U4>::from_num(4)
+        );
+        assert_eq!(
+            FixedI8::<U4>::overflowing_from_fixed(big),
+            (FixedI8::<U4>::from_num(4), true)
+        );
+        assert_eq!(
+            FixedI8::<U4>::overflowing_from_fixed(small),
+            (FixedI8::<U4>::from_num(3.5), false)
+        );
+        // extra fractional bits are truncated
+        let fine = FixedI16::<U8>::from_bits(0x0181);
+        assert_eq!(
+            FixedI8::<U4>::checked_from_fixed(fine),
+            Some(FixedI8::<U4>::from_bits(0x18))
+        );
+        // widening is lossless
+        let wide = FixedI32::<U8>::checked_from_fixed(fine).unwrap();
+        assert_eq!(wide.to_bits(), 0x0181);
+        assert_eq!(FixedI32::<U8>::from_fixed(big), 100);
+    }
+
+    #[test]
+    fn const_nbits() {
+        use crate::types::{I16F16, U0F8, U8F8};
+        const FRAC_BYTES: usize = (I16F16::FRAC_NBITS / 8) as usize;
+        const INT_NBITS: u32 = U8F8::INT_NBITS;
+        let a = [0u8; (I16F16::NBITS / 8) as usize];
+        let b = [0u8; FRAC_BYTES];
+        assert_eq!(a.len(), 4);
+        assert_eq!(b.len(), 2);
+        assert_eq!(INT_NBITS, 8);
+        assert_eq!(U8F8::int_nbits(), 8);
+        assert_eq!(I16F16::frac_nbits(), 16);
+        assert_eq!(U0F8::NBITS, 8);
+        assert_eq!(U0F8::INT_NBITS, 0);
+    }
+
+    #[test]
+    fn generic_byte_slice_decode() {
+        use crate::{
+            traits::Fixed,
+            types::{I32F32, U8F8},
+        };
+        fn round_trip<F: Fixed>(x: F) -> Option<F> {
+            let mut buf = [0u8; 16];
+            let le = x.to_le_bytes();
+            let len = le.as_ref().len();
+            buf[..len].copy_from_slice(le.as_ref());
+            assert_eq!(F::from_le_byte_slice(&buf[..len + 1]), None);
+            assert_eq!(F::from_le_byte_slice(&buf[..len - 1]), None);
+            let be = x.to_be_bytes();
+            assert_eq!(F::from_be_byte_slice(be.as_ref()), Some(x));
+            F::from_le_byte_slice(&buf[..len])
+        }
+        let a = I32F32::from_num(-12345.678);
+        let b = U8F8::from_num(200.25);
+        assert_eq!(round_trip(a), Some(a));
+        assert_eq!(round_trip(b), Some(b));
+        assert_eq!(U8F8::from_le_byte_slice(&[0x40, 0xC8]), Some(b));
+    }
+
+    #[test]
+    fn unsigned_signed_assoc_types() {
+        use crate::{
+            traits::Fixed,
+            types::{I16F16, I8F8, U16F16, U8F8},
+        };
+        fn magnitude<F: Fixed>(x: F) -> F::Unsigned {
+            x.unsigned_abs()
+        }
+        fn to_signed<F: Fixed>(x: F) -> F::Signed {
+            F::Signed::from_num(x)
+        }
+        let m: U16F16 = magnitude(I16F16::from_num(-1.25));
+        assert_eq!(m, U16F16::from_num(1.25));
+        let m: U16F16 = magnitude(U16F16::from_num(1.25));
+        assert_eq!(m, U16F16::from_num(1.25));
+        assert_eq!(magnitude(I8F8::min_value()), U8F8::from_num(128));
+        let s: I8F8 = to_signed(U8F8::from_num(3.5));
+        assert_eq!(s, I8F8::from_num(3.5));
+        assert_eq!(
+            I8F8::from_num(-3).abs_diff(I8F8::from_num(4)),
+            U8F8::from_num(7)
+        );
+    }
+
+    #[test]
+    fn next_multiple_of() {
+        use crate::types::{I16F16, U8F8};
+        let step = I16F16::from_num(2.5);
+        assert_eq!(I16F16::from_num(7).next_multiple_of(step), 7.5);
+        assert_eq!(I16F16::from_num(7.5).next_multiple_of(step), 7.5);
+        assert_eq!(I16F16::from_num(0).next_multiple_of(step), 0);
+        assert_eq!(I16F16::from_num(-7).next_multiple_of(step), -5);
+        assert_eq!(I16F16::from_num(7).next_multiple_of(-step), 5);
+        assert_eq!(I16F16::from_num(-7).next_multiple_of(-step), -7.5);
+        let delta = I16F16::from_bits(1);
+        assert_eq!(
+            I16F16::min_value().next_multiple_of(-delta),
+            I16F16::min_value()
+        );
+        assert_eq!(I16F16::max_value().checked_next_multiple_of(step), None);
+        assert_eq!(
+            I16F16::from_num(1).checked_next_multiple_of(I16F16::from_num(0)),
+            None
+        );
+        assert_eq!(I16F16::min_value().checked_next_multiple_of(-step), None);
+        assert_eq!(
+            I16F16::from_num(-7).checked_next_multiple_of(step),
+            Some(I16F16::from_num(-5))
+        );
+        assert_eq!(
+            U8F8::from_num(3.1).next_multiple_of(U8F8::from_num(0.5)),
+            3.5
+        );
+        assert_eq!(
+            U8F8::max_value().checked_next_multiple_of(U8F8::from_num(2)),
+            None
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "remainder with a divisor of zero")]
+    fn next_multiple_of_zero() {
+        use crate::types::U8F8;
+        let _ = U8F8::from_num(3).next_multiple_of(U8F8::from_num(0));
+    }
+
+    #[test]
+    fn from_bool() {
+        use crate::types::{I0F8, I1F7, I2F6, I8F8, U0F8, U1F7, U8F8};
+        assert_eq!(U8F8::from_bool(true), 1);
+        assert_eq!(U8F8::from_bool(false), 0);
+        assert_eq!(I8F8::from_bool(true), I8F8::from_num(true));
+        assert_eq!(U1F7::from_bool(true), 1);
+        assert_eq!(I2F6::from_bool(true), 1);
+        // zero can always be represented
+        assert_eq!(I1F7::from_bool(false), 0);
+        assert_eq!(U0F8::from_bool(false), 0);
+        assert_eq!(I0F8::from_bool(false), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "one cannot be represented")]
+    fn from_bool_not_representable() {
+        use crate::types::I1F7;
+        let _ = I1F7::from_bool(true);
+    }
+
+    #[test]
+    fn div_exact() {
+        use crate::{
+            types::{I0F128, I16F16, I8F8, U0F128, U128F0, U8F8},
+            DivError,
+        };
+        let one = I16F16::from_num(1);
+        let three = I16F16::from_num(3);
+        assert_eq!(
+            I16F16::from_num(7.5).div_exact(I16F16::from_num(2.5)),
+            Ok(three)
+        );
+        assert_eq!(
+            one.div_exact(I16F16::from_num(-4)),
+            Ok(I16F16::from_num(-0.25))
+        );
+        assert_eq!(one.div_exact(three), Err(DivError::Inexact(one / three)));
+        assert_eq!(
+            (-one).div_exact(three),
+            Err(DivError::Inexact(-one / three))
+        );
+        assert_eq!(one.div_exact(I16F16::from_num(0)), Err(DivError::DivByZero));
+        assert_eq!(
+            I8F8::from_num(100).div_exact(I8F8::from_num(0.5)),
+            Err(DivError::Overflow)
+        );
+        assert_eq!(
+            I8F8::min_value().div_exact(I8F8::from_num(-1)),
+            Err(DivError::Overflow)
+        );
+        let one = U8F8::from_num(1);
+        let div = U8F8::from_bits(257);
+        assert_eq!(one.div_exact(div), Err(DivError::Inexact(one / div)));
+        assert_eq!(
+            U128F0::from_num(9).div_exact(U128F0::from_num(3)),
+            Ok(U128F0::from_num(3))
+        );
+        assert_eq!(
+            U128F0::from_num(10).div_exact(U128F0::from_num(3)),
+            Err(DivError::Inexact(U128F0::from_num(3)))
+        );
+
+        let quarter = U0F128::from_num(0.25);
+        let half = U0F128::from_num(0.5);
+        assert_eq!(quarter.div_exact(half), Ok(half));
+        assert_eq!(half.div_exact(half), Err(DivError::Overflow));
+        let delta = U0F128::from_bits(1);
+        assert_eq!(
+            delta.div_exact(U0F128::from_num(0.75)),
+            Err(DivError::Inexact(delta))
+        );
+        let eighth = I0F128::from_num(-0.125);
+        let quarter = I0F128::from_num(0.25);
+        let half = I0F128::from_num(-0.5);
+        assert_eq!(eighth.div_exact(quarter), Ok(half));
+        assert_eq!(eighth.div_exact(-quarter), Err(DivError::Overflow));
+    }
+
+    #[test]
+    fn div_all_frac_bits() {
+        use crate::types::{I0F128, U0F128};
+        let quarter = U0F128::from_num(0.25);
+        let half = U0F128::from_num(0.5);
+        assert_eq!(quarter / half, half);
+        assert_eq!(quarter.checked_div(half), Some(half));
+        assert_eq!(half.checked_div(half), None);
+        assert_eq!(half.checked_div(U0F128::from_num(0)), None);
+        let eighth = I0F128::from_num(-0.125);
+        let quarter = I0F128::from_num(0.25);
+        let half = I0F128::from_num(-0.5);
+        assert_eq!(eighth / quarter, half);
+        assert_eq!(eighth.checked_div(quarter), Some(half));
+        assert_eq!(eighth.checked_div(-quarter), None);
+    }
+
+    #[test]
+    fn midpoint() {
+        use crate::types::{I8F8, U128F0, U8F8};
+        assert_eq!(
+            I8F8::max_value().midpoint(I8F8::max_value()),
+            I8F8::max_value()
+        );
+        assert_eq!(
+            I8F8::min_value().midpoint(I8F8::min_value()),
+            I8F8::min_value()
+        );
+        assert_eq!(
+            U8F8::max_value().midpoint(U8F8::max_value()),
+            U8F8::max_value()
+        );
+        assert_eq!(I8F8::max_value().midpoint(I8F8::min_value()), 0);
+        assert_eq!(I8F8::from_num(-3).midpoint(I8F8::from_num(1.5)), -0.75);
+        // mixed signs with an odd sum of bits: rounded towards zero
+        let pos = I8F8::from_bits(4);
+        let neg = I8F8::from_bits(-7);
+        assert_eq!(pos.midpoint(neg).to_bits(), -1);
+        assert_eq!(neg.midpoint(pos).to_bits(), -1);
+        assert_eq!(
+            I8F8::from_bits(7).midpoint(I8F8::from_bits(-4)).to_bits(),
+            1
+        );
+        assert_eq!(
+            U128F0::max_value().midpoint(U128F0::max_value() - U128F0::from_num(1)),
+            U128F0::max_value() - U128F0::from_num(1)
+        );
+    }
+
+    #[test]
+    fn duration_secs() {
+        use crate::types::{I0F128, I32F32, I8F8, U64F64};
+        use core::time::Duration;
+        let d = Duration::from_millis(1500);
+        let secs = I32F32::from_duration_secs(d).unwrap();
+        assert_eq!(secs, I32F32::from_num(1.5));
+        assert_eq!(secs.to_duration_secs(), d);
+
+        let d = Duration::new(3, 1);
+        let secs = I32F32::from_duration_secs(d).unwrap();
+        assert_eq!(secs.to_duration_secs(), d);
+        let nano = I0F128::from_duration_secs(Duration::new(0, 1)).unwrap();
+        assert!((nano - I0F128::from_num(1e-9)).abs() < I0F128::from_bits(1 << 64));
+        assert_eq!(
+            U64F64::from_duration_secs(Duration::new(u64::MAX, 999_999_999))
+                .unwrap()
+                .int(),
+            u64::MAX
+        );
+        assert_eq!(I8F8::from_duration_secs(Duration::from_secs(128)), None);
+        // 127.999 s rounds up to 128, which overflows
+        assert_eq!(
+            I8F8::from_duration_secs(Duration::from_millis(127_999)),
+            None
+        );
+        assert_eq!(
+            I8F8::from_duration_secs(Duration::from_millis(127_998)),
+            Some(I8F8::max_value())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "duration out of range")]
+    fn negative_to_duration_secs() {
+        use crate::types::I32F32;
+        let _ = I32F32::from_num(-0.5).to_duration_secs();
+    }
+
+    #[test]
+    fn from_int_frac() {
+        use crate::types::{I0F128, I0F8, I16F16, I8F8, U0F128, U0F8, U8F8};
+        assert_eq!(I16F16::from_int_frac(3, 1, 4), Some(I16F16::from_num(3.25)));
+        assert_eq!(
+            I16F16::from_int_frac(3, 1, 4).unwrap().to_bits(),
+            0x0003_4000
+        );
+        assert_eq!(
+            I16F16::from_int_frac(3, 1, 3).unwrap().to_bits(),
+            0x0003_5555
+        );
+        assert_eq!(
+            I16F16::from_int_frac(3, 2, 3).unwrap().to_bits(),
+            0x0003_AAAB
+        );
+        assert_eq!(
+            I16F16::from_int_frac(3, -1, 4),
+            Some(I16F16::from_num(2.75))
+        );
+        assert_eq!(
+            I16F16::from_int_frac(-3, 1, -4),
+            Some(I16F16::from_num(-3.25))
+        );
+        assert_eq!(I16F16::from_int_frac(0, 7, 2), Some(I16F16::from_num(3.5)));
+        assert_eq!(I16F16::from_int_frac(3, 1, 0), None);
+
+        // ties round to even
+        assert_eq!(U8F8::from_int_frac(0, 1, 512), Some(U8F8::from_bits(0)));
+        assert_eq!(U8F8::from_int_frac(0, 3, 512), Some(U8F8::from_bits(2)));
+        assert_eq!(I8F8::from_int_frac(0, -3, 512), Some(I8F8::from_bits(-2)));
+
+        // no integer bits
+        assert_eq!(U0F8::from_int_frac(0, 255, 255), None);
+        assert_eq!(U0F8::from_int_frac(0, 127, 128), Some(U0F8::from_bits(254)));
+        assert_eq!(I0F8::from_int_frac(0, -1, 2), Some(I0F8::from_num(-0.5)));
+        assert_eq!(I0F8::from_int_frac(0, 1, 2), None);
+
+        // overflow
+        assert_eq!(I8F8::from_int_frac(127, 1, 1), None);
+        assert_eq!(
+            I8F8::from_int_frac(127, 255, 256),
+            Some(I8F8::from_bits(0x7FFF))
+        );
+        assert_eq!(I8F8::from_int_frac(-128, -1, 256), None);
+        assert_eq!(I8F8::from_int_frac(i16::MIN, 1, 1), None);
+        assert_eq!(I8F8::from_int_frac(-128, 0, 1), Some(I8F8::min_value()));
+        assert_eq!(U8F8::from_int_frac(1, 0, 1), Some(U8F8::from_num(1)));
+
+        // all bits fractional in the widest types
+        assert_eq!(
+            U0F128::from_int_frac(0, 1, 3),
+            Some(U0F128::from_bits(u128::MAX / 3))
+        );
+        assert_eq!(
+            U0F128::from_int_frac(0, 2, 3),
+            Some(U0F128::from_bits(u128::MAX / 3 * 2 + 1))
+        );
+        assert_eq!(
+            I0F128::from_int_frac(0, -1, 4),
+            Some(I0F128::from_num(-0.25))
+        );
+        assert_eq!(
+            I0F128::from_int_frac(0, 1, -2),
+            Some(I0F128::from_num(-0.5))
+        );
+        assert_eq!(I0F128::from_int_frac(0, 1, 2), None);
+        assert_eq!(U0F128::from_int_frac(0, 1, 1), None);
+    }
+
+    #[test]
+    fn saturating_mul_div_int() {
+        use crate::types::{I8F8, U8F8};
+        assert_eq!(U8F8::max_value().saturating_mul_int(2), U8F8::max_value());
+        assert_eq!(
+            U8F8::from_num(1.5).saturating_mul_int(3),
+            U8F8::from_num(4.5)
+        );
+        assert_eq!(
+            I8F8::from_num(-100).saturating_mul_int(2),
+            I8F8::min_value()
+        );
+        assert_eq!(
+            I8F8::from_num(100).saturating_mul_int(-2),
+            I8F8::min_value()
+        );
+        assert_eq!(
+            I8F8::from_num(-100).saturating_mul_int(-2),
+            I8F8::max_value()
+        );
+
+        assert_eq!(
+            U8F8::from_num(4.5).saturating_div_int(3),
+            U8F8::from_num(1.5)
+        );
+        assert_eq!(
+            I8F8::from_num(-4.5).saturating_div_int(3),
+            I8F8::from_num(-1.5)
+        );
+        assert_eq!(I8F8::min_value().saturating_div_int(-1), I8F8::max_value());
+        assert_eq!(I8F8::min_value().saturating_div_int(1), I8F8::min_value());
+    }
+
+    #[test]
+    fn generic_to_from_fixed_round_trip() {
+        use crate::{
+            traits::{Fixed, FromFixed, ToFixed},
+            types::{I16F16, I32F32, I8F8, U16F16},
+        };
+        fn load<T: FromFixed>(f: I16F16) -> T {
+            T::from_fixed(f)
+        }
+        fn store<T: ToFixed>(val: T) -> I16F16 {
+            val.to_fixed()
+        }
+        fn round_trip<T: FromFixed + ToFixed>(f: I16F16) -> I16F16 {
+            store(load::<T>(f))
+        }
+        fn checked_round_trip<F: Fixed, T: FromFixed + ToFixed>(f: I16F16) -> Option<I16F16> {
+            T::checked_from_fixed(f)?
+                .checked_to_fixed::<F>()?
+                .checked_to_fixed()
+        }
+
+        let f = I16F16::from_num(-12.75);
+        assert_eq!(round_trip::<f32>(f), f);
+        assert_eq!(round_trip::<f64>(f), f);
+        assert_eq!(round_trip::<I8F8>(f), f);
+        assert_eq!(round_trip::<I32F32>(f), f);
+        assert_eq!(round_trip::<i32>(f), I16F16::from_num(-13));
+        assert_eq!(round_trip::<i64>(I16F16::from_num(7)), 7);
+
+        assert_eq!(checked_round_trip::<I32F32, f64>(f), Some(f));
+        assert_eq!(
+            checked_round_trip::<I8F8, i16>(f),
+            Some(I16F16::from_num(-13))
+        );
+        assert_eq!(checked_round_trip::<U16F16, f32>(f), None);
+        assert_eq!(checked_round_trip::<I8F8, u8>(f), None);
+
+        let big = I16F16::from_num(300);
+        assert_eq!(i8::saturating_from_fixed(big), i8::MAX);
+        assert_eq!(big.saturating_to_fixed::<I8F8>(), I8F8::max_value());
+        assert_eq!(u8::wrapping_from_fixed(big), 44);
+        assert_eq!(u8::overflowing_from_fixed(big), (44, true));
+        assert_eq!(
+            300u16.overflowing_to_fixed::<I8F8>(),
+            (I8F8::from_num(44), true)
+        );
+        assert_eq!(store(1.5f64), I16F16::from_num(1.5));
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    fn f16_methods() {
+        use crate::types::{I16F16, I8F8, U0F16, U16F16};
+        use half::f16;
+        for &val in &[0.0, 1.75, -3.5, 1000.25, -0.001, 12345.678] {
+            let half = f16::from_f32(val);
+            assert_eq!(I16F16::from_f16(half), I16F16::from_num(half));
+            assert_eq!(
+                I16F16::checked_from_f16(half),
+                I16F16::checked_from_num(half)
+            );
+            assert_eq!(
+                I16F16::saturating_from_f16(half),
+                I16F16::saturating_from_num(half)
+            );
+            let fix = I16F16::from_num(val);
+            assert_eq!(fix.to_f16(), fix.to_num::<f16>());
+        }
+        assert_eq!(I8F8::checked_from_f16(f16::from_f32(1000.0)), None);
+        assert_eq!(
+            I8F8::saturating_from_f16(f16::NEG_INFINITY),
+            I8F8::min_value()
+        );
+        assert_eq!(U0F16::checked_from_f16(f16::NAN), None);
+
+        // only 11 significant bits are kept
+        let fix = I16F16::from_num(1025.75);
+        assert_eq!(fix.to_f16(), f16::from_f32(1026.0));
+        assert_eq!(U16F16::from_num(65504).to_f16(), f16::MAX);
+        assert_eq!(U16F16::from_num(65519.99).to_f16(), f16::MAX);
+        assert_eq!(U16F16::from_num(65520).to_f16(), f16::INFINITY);
+    }
+
+    #[test]
+    fn checked_sum_product() {
+        use crate::types::{I8F8, U0F8, U8F8};
+        let values = [
+            U8F8::from_num(100),
+            U8F8::from_num(100),
+            U8F8::from_num(100),
+        ];
+        assert_eq!(U8F8::checked_sum(values.iter().cloned()), None);
+        assert_eq!(
+            U8F8::checked_sum(values[..2].iter().cloned()),
+            Some(U8F8::from_num(200))
+        );
+        assert_eq!(
+            U8F8::checked_sum(values[..0].iter().cloned()),
+            Some(U8F8::from_num(0))
+        );
+        // an intermediate overflow is reported even if the total fits
+        let values = [I8F8::max_value(), I8F8::from_num(1), I8F8::from_num(-2)];
+        assert_eq!(I8F8::checked_sum(values.iter().cloned()), None);
+
+        let values = [U8F8::from_num(10), U8F8::from_num(10), U8F8::from_num(3)];
+        assert_eq!(U8F8::checked_product(values.iter().cloned()), None);
+        assert_eq!(
+            U8F8::checked_product(values[..2].iter().cloned()),
+            Some(U8F8::from_num(100))
+        );
+        assert_eq!(
+            U8F8::checked_product(values[..0].iter().cloned()),
+            Some(U8F8::from_num(1))
+        );
+        assert_eq!(U0F8::checked_product(core::iter::empty()), None);
+        let half = U0F8::from_num(0.5);
+        assert_eq!(
+            U0F8::checked_product([half, half].iter().cloned()),
+            Some(U0F8::from_num(0.25))
+        );
+    }
+
+    #[test]
+    fn bits_unsigned() {
+        use crate::types::{I0F128, I8F8, U8F8};
+        assert_eq!(I8F8::from_num(-1).to_bits_unsigned(), 0xFF00u16);
+        assert_eq!(I8F8::from_num(-0.5).to_bits_unsigned(), 0xFF80u16);
+        assert_eq!(I8F8::min_value().to_bits_unsigned(), 0x8000u16);
+        assert_eq!(I8F8::from_bits_unsigned(0xFF00), I8F8::from_num(-1));
+        assert_eq!(I8F8::from_bits_unsigned(0x7FFF), I8F8::max_value());
+        assert_eq!(U8F8::from_num(1.5).to_bits_unsigned(), 0x0180);
+        assert_eq!(U8F8::from_bits_unsigned(0x0180), U8F8::from_num(1.5));
+        let x = I0F128::from_bits(-3);
+        assert_eq!(I0F128::from_bits_unsigned(x.to_bits_unsigned()), x);
+        assert_eq!(x.to_bits_unsigned(), (-3i128) as u128);
+    }
 }
@@ -19,9 +19,28 @@ macro_rules! fixed_frac {
         $Fixed:ident[$s_fixed:expr](
             $Inner:ty[$s_inner:expr], $LeEqU:tt, $s_nbits:expr, $s_nbits_m4:expr
         ),
-        $UInner:ty, $Signedness:tt
+        $UFixed:ident($UInner:ty), $Signedness:tt
     ) => {
         impl<Frac: $LeEqU> $Fixed<Frac> {
+            comment! {
+                "The number of bits.
+
+The constant can be used in constant expressions, for example to size
+an array.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U6, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U6>;
+assert_eq!(Fix::NBITS, ", $s_nbits, ");
+let bytes = [0u8; (Fix::NBITS / 8) as usize];
+assert_eq!(bytes.len() * 8, ", $s_nbits, ");
+```
+";
+                pub const NBITS: u32 = mem::size_of::<$Inner>() as u32 * 8;
+            }
+
             comment! {
                 "The number of integer bits.
 
@@ -33,7 +52,7 @@ type Fix = ", $s_fixed, "<U6>;
 assert_eq!(Fix::INT_NBITS, ", $s_nbits, " - 6);
 ```
 ";
-                pub const INT_NBITS: u32 = mem::size_of::<$Inner>() as u32 * 8 - Self::FRAC_NBITS;
+                pub const INT_NBITS: u32 = Self::NBITS - Self::FRAC_NBITS;
             }
 
             comment! {
@@ -137,6 +156,166 @@ assert_eq!(Fix::from_num(-5).signum(), -1);
                         }
                     }
                 }
+
+                comment! {
+                    "Checked signum. Returns a number representing the
+sign of `self`, or [`None`] on overflow.
+
+Overflow can only occur
+  * if the value is positive and the fixed-point number has zero
+    or one integer bits such that it cannot hold the value 1.
+  * if the value is negative and the fixed-point number has zero
+    integer bits, such that it cannot hold the value −1.
+
+# Examples
+
+```rust
+use substrate_fixed::{
+    types::extra::{U4, U", $s_nbits, "},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(5).checked_signum(), Some(Fix::from_num(1)));
+assert_eq!(Fix::from_num(0).checked_signum(), Some(Fix::from_num(0)));
+assert_eq!(Fix::from_num(-5).checked_signum(), Some(Fix::from_num(-1)));
+
+type ZeroIntBits = ", $s_fixed, "<U", $s_nbits, ">;
+assert_eq!(ZeroIntBits::from_num(0.25).checked_signum(), None);
+assert_eq!(ZeroIntBits::from_num(-0.25).checked_signum(), None);
+```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+";
+                    #[inline]
+                    pub fn checked_signum(self) -> Option<$Fixed<Frac>> {
+                        match self.to_bits().cmp(&0) {
+                            Ordering::Equal => Some(Self::from_bits(0)),
+                            Ordering::Greater => Self::checked_from_num(1),
+                            Ordering::Less => Self::checked_from_num(-1),
+                        }
+                    }
+                }
+
+                comment! {
+                    "Saturating signum. Returns a number representing
+the sign of `self`, saturating on overflow.
+
+Overflow can only occur
+  * if the value is positive and the fixed-point number has zero
+    or one integer bits such that it cannot hold the value 1, in
+    which case the maximum value is returned.
+  * if the value is negative and the fixed-point number has zero
+    integer bits, such that it cannot hold the value −1, in which
+    case the minimum value is returned.
+
+# Examples
+
+```rust
+use substrate_fixed::{
+    types::extra::{U4, U", $s_nbits, "},
+    ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(5).saturating_signum(), 1);
+assert_eq!(Fix::from_num(0).saturating_signum(), 0);
+assert_eq!(Fix::from_num(-5).saturating_signum(), -1);
+
+type ZeroIntBits = ", $s_fixed, "<U", $s_nbits, ">;
+assert_eq!(ZeroIntBits::from_num(0.25).saturating_signum(), ZeroIntBits::max_value());
+assert_eq!(ZeroIntBits::from_num(-0.25).saturating_signum(), ZeroIntBits::min_value());
+```
+";
+                    #[inline]
+                    pub fn saturating_signum(self) -> $Fixed<Frac> {
+                        match self.to_bits().cmp(&0) {
+                            Ordering::Equal => Self::from_bits(0),
+                            Ordering::Greater => Self::saturating_from_num(1),
+                            Ordering::Less => Self::saturating_from_num(-1),
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Multiply and add. Returns `self` × `mul` + `add`.
+
+The product is computed with double the width before the addition, so
+that the result is correct as long as the final value fits, even if
+the intermediate product does not. Like multiplication, the product is
+truncated to the precision of the type.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result
+overflows. When debug assertions are not enabled, the wrapped value can
+be returned, but it is not considered a breaking change if in the
+future it panics; if wrapping is required use [`checked_mul_add`]
+instead.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(4).mul_add(Fix::from_num(0.5), Fix::from_num(3)), 5);
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "// MAX × 2 does not fit, but MAX × 2 − MAX = MAX does
+assert_eq!(Fix::max_value().", "mul_add", "(Fix::from_num(2), -Fix::max_value()), ", "Fix::max_value()", ");
+",
+                },
+                "```
+
+[`checked_mul_add`]: #method.checked_mul_add
+";
+                #[inline]
+                pub fn mul_add(self, mul: $Fixed<Frac>, add: $Fixed<Frac>) -> $Fixed<Frac> {
+                    let (ans, overflow) =
+                        self.to_bits()
+                            .mul_add_overflow(mul.to_bits(), add.to_bits(), Frac::U32);
+                    debug_assert!(!overflow, "overflow");
+                    Self::from_bits(ans)
+                }
+            }
+
+            comment! {
+                "Linear interpolation between `self` and `other`.
+Returns `self` + (`other` − `self`) × `t`.
+
+The difference is computed without overflow even when `self` and
+`other` are at opposite ends of the range, so the result is correct as
+long as it fits. In particular, when `t` is zero the result is exactly
+`self`, and when `t` is one the result is exactly `other`. The product
+is truncated towards zero.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result
+overflows, which can only happen when `t` is outside [0, 1]. When debug
+assertions are not enabled, the wrapped value can be returned, but it is
+not considered a breaking change if in the future it panics; if wrapping
+is required use [`checked_lerp`] instead.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let (a, b) = (Fix::from_num(1), Fix::from_num(5));
+assert_eq!(a.lerp(b, Fix::from_num(0.5)), 3);
+assert_eq!(a.lerp(b, Fix::from_num(0.25)), 2);
+assert_eq!(b.lerp(a, Fix::from_num(0.25)), 4);
+```
+
+[`checked_lerp`]: #method.checked_lerp
+";
+                #[inline]
+                pub fn lerp(self, other: $Fixed<Frac>, t: $Fixed<Frac>) -> $Fixed<Frac> {
+                    let (ans, overflow) = self.lerp_overflow(other, t);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
             }
 
             comment! {
@@ -185,6 +364,55 @@ assert_eq!(Fix::from_num(7.5).div_euclid(Fix::from_num(2)), Fix::from_num(3));
                 }
             }
 
+            comment! {
+                "Division with remainder.
+
+Returns a [tuple] of the quotient rounded towards zero and the
+remainder. The quotient is a fixed-point number with an integer value,
+like the quotient of [`div_euclid`], and the remainder is the same as
+`self % rhs`, so that `quotient * rhs + remainder == self`.
+
+# Panics
+
+Panics if the divisor is zero.
+
+When debug assertions are enabled, this method also panics if the
+quotient overflows. When debug assertions are not enabled, the wrapped
+value can be returned, but it is not considered a breaking change if in
+the future it panics.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let (q, r) = Fix::from_num(7).div_rem(Fix::from_num(2));
+assert_eq!((q, r), (Fix::from_num(3), Fix::from_num(1)));
+let (q, r) = Fix::from_num(7.5).div_rem(Fix::from_num(1.75));
+assert_eq!((q, r), (Fix::from_num(4), Fix::from_num(0.5)));
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "let (q, r) = Fix::from_num(-7.5).div_rem(Fix::from_num(1.75));
+assert_eq!((q, r), (Fix::from_num(-4), Fix::from_num(-0.5)));
+",
+                },
+                "```
+
+[`div_euclid`]: #method.div_euclid
+[tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+";
+                #[inline]
+                pub fn div_rem(self, rhs: $Fixed<Frac>) -> ($Fixed<Frac>, $Fixed<Frac>) {
+                    // both operands have the same scale, so the integer
+                    // quotient of the bits is the integer quotient of the values
+                    let (int, int_overflow) = self.to_bits().overflowing_div(rhs.to_bits());
+                    let (quot, overflow) = Self::overflowing_from_num(int);
+                    debug_assert!(!int_overflow && !overflow, "overflow");
+                    (quot, self % rhs)
+                }
+            }
+
             comment! {
                 "Euclidean division by an integer.
 
@@ -288,6 +516,139 @@ assert_eq!(Fix::max_value().checked_mul(Fix::from_num(2)), None);
                 }
             }
 
+            comment! {
+                "Checked product. Returns the product of the values, or
+[`None`] on the first overflow.
+
+The product of no values is one, so [`None`] is also returned for an
+empty iterator if one cannot be represented. Unlike [`Product`], this
+never panics.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let values = [Fix::from_num(1.5), Fix::from_num(3)];
+assert_eq!(Fix::checked_product(values.iter().cloned()), Some(Fix::from_num(4.5)));
+let values = [Fix::max_value(), Fix::from_num(2)];
+assert_eq!(Fix::checked_product(values.iter().cloned()), None);
+```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+[`Product`]: https://doc.rust-lang.org/nightly/core/iter/trait.Product.html
+";
+                #[inline]
+                pub fn checked_product<I>(mut iter: I) -> Option<$Fixed<Frac>>
+                where
+                    I: Iterator<Item = $Fixed<Frac>>,
+                {
+                    match iter.next() {
+                        None => Self::checked_from_num(1),
+                        Some(first) => iter.try_fold(first, Self::checked_mul),
+                    }
+                }
+            }
+
+            comment! {
+                "Checked multiply and add. Returns `self` × `mul` + `add`,
+or [`None`] on overflow.
+
+The product is computed with double the width before the addition, so
+only the final value has to fit.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(
+    Fix::from_num(4).checked_mul_add(Fix::from_num(0.5), Fix::from_num(3)),
+    Some(Fix::from_num(5))
+);
+assert_eq!(Fix::max_value().checked_mul_add(Fix::from_num(1), Fix::DELTA), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "// MAX × 2 does not fit, but MAX × 2 − MAX = MAX does
+assert_eq!(Fix::max_value().", "checked_mul_add", "(Fix::from_num(2), -Fix::max_value()), ", "Some(Fix::max_value())", ");
+",
+                },
+                "```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+";
+                #[inline]
+                pub fn checked_mul_add(
+                    self,
+                    mul: $Fixed<Frac>,
+                    add: $Fixed<Frac>,
+                ) -> Option<$Fixed<Frac>> {
+                    match self
+                        .to_bits()
+                        .mul_add_overflow(mul.to_bits(), add.to_bits(), Frac::U32)
+                    {
+                        (ans, false) => Some(Self::from_bits(ans)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked linear interpolation between `self` and
+`other`. Returns `self` + (`other` − `self`) × `t`, or [`None`] on
+overflow.
+
+Overflow can only occur when `t` is outside [0, 1].
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let (a, b) = (Fix::from_num(1), Fix::from_num(5));
+assert_eq!(a.checked_lerp(b, Fix::from_num(0.5)), Some(Fix::from_num(3)));
+assert_eq!(a.checked_lerp(b, Fix::from_num(1)), Some(b));
+assert_eq!(Fix::min_value().checked_lerp(Fix::max_value(), Fix::from_num(1.5)), None);
+```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+";
+                #[inline]
+                pub fn checked_lerp(
+                    self,
+                    other: $Fixed<Frac>,
+                    t: $Fixed<Frac>,
+                ) -> Option<$Fixed<Frac>> {
+                    match self.lerp_overflow(other, t) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            #[inline]
+            fn lerp_overflow(self, other: $Fixed<Frac>, t: $Fixed<Frac>) -> ($Fixed<Frac>, bool) {
+                let start = self.to_bits();
+                let diff_neg = other.to_bits() < start;
+                let diff_abs = self.abs_diff(other).to_bits();
+                let t = t.to_bits();
+                let (t_neg, t_abs) = if_signed_unsigned! {
+                    $Signedness,
+                    (t < 0, t.wrapping_abs() as $UInner),
+                    (false, t),
+                };
+                let (prod, overflow) = diff_abs.mul_overflow(t_abs, Frac::U32);
+                let (ans, overflow) = if diff_neg != t_neg {
+                    let room = (start as $UInner).wrapping_sub(<$Inner>::min_value() as $UInner);
+                    ((start as $UInner).wrapping_sub(prod), overflow || prod > room)
+                } else {
+                    let room = (<$Inner>::max_value() as $UInner).wrapping_sub(start as $UInner);
+                    ((start as $UInner).wrapping_add(prod), overflow || prod > room)
+                };
+                (Self::from_bits(ans as $Inner), overflow)
+            }
+
             comment! {
                 "Checked division. Returns the quotient, or [`None`] if
 the divisor is zero or on overflow.
@@ -315,6 +676,47 @@ assert_eq!(Fix::max_value().checked_div(Fix::from_num(1) / 2), None);
                 }
             }
 
+            comment! {
+                "Exact division. Returns the quotient if it can be
+represented exactly, otherwise returns an error.
+
+The error is [`DivByZero`] if the divisor is zero, [`Overflow`] if the
+quotient does not fit, and [`Inexact`] holding the quotient truncated
+towards zero if the quotient has more fractional bits than the type
+can hold.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, ", DivError};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(7.5).div_exact(Fix::from_num(2.5)), Ok(Fix::from_num(3)));
+// 1/3 truncated to four fractional bits is 0.0101 in binary
+assert_eq!(
+    Fix::from_num(1).div_exact(Fix::from_num(3)),
+    Err(DivError::Inexact(Fix::from_bits(0b0101)))
+);
+assert_eq!(Fix::from_num(1).div_exact(Fix::from_num(0)), Err(DivError::DivByZero));
+assert_eq!(Fix::max_value().div_exact(Fix::from_num(0.5)), Err(DivError::Overflow));
+```
+
+[`DivByZero`]: enum.DivError.html#variant.DivByZero
+[`Inexact`]: enum.DivError.html#variant.Inexact
+[`Overflow`]: enum.DivError.html#variant.Overflow
+";
+                #[inline]
+                pub fn div_exact(self, rhs: $Fixed<Frac>) -> Result<$Fixed<Frac>, DivError<$Fixed<Frac>>> {
+                    if rhs.to_bits() == 0 {
+                        return Err(DivError::DivByZero);
+                    }
+                    match self.to_bits().div_exact_overflow(rhs.to_bits(), Frac::U32) {
+                        (_, true, _) => Err(DivError::Overflow),
+                        (ans, false, true) => Ok(Self::from_bits(ans)),
+                        (ans, false, false) => Err(DivError::Inexact(Self::from_bits(ans))),
+                    }
+                }
+            }
+
             comment! {
                 "Checked Euclidean division. Returns the quotient, or
 [`None`] if the divisor is zero or on overflow.
@@ -775,6 +1177,28 @@ assert_eq!(Fix::max_value().overflowing_mul(Fix::from_num(4)), (wrapped, true));
                 }
             }
 
+            comment! {
+                "Returns [`true`][`bool`] if the multiplication would overflow.
+
+This is equivalent to `self.overflowing_mul(rhs).1`.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert!(!Fix::max_value().mul_overflows(Fix::from_num(1)));
+assert!(Fix::max_value().mul_overflows(Fix::from_num(2)));
+```
+
+[`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+";
+                #[inline]
+                pub fn mul_overflows(self, rhs: $Fixed<Frac>) -> bool {
+                    self.to_bits().mul_overflow(rhs.to_bits(), Frac::U32).1
+                }
+            }
+
             comment! {
                 "Overflowing division.
 
@@ -991,6 +1415,408 @@ assert_eq!(Fix::from_num(-7.5).overflowing_rem_euclid_int(20), (Fix::from_num(-3
                 }
             }
 
+            comment! {
+                "Returns the error of `self` with respect to the exact
+rational number `num`/`den`, that is `self` − `num`/`den`.
+
+The difference is computed exactly and then rounded once to the
+nearest, with ties rounded to even.
+
+# Panics
+
+Panics if `den` is zero or if the error does not fit.
+
+# Examples
+
+```rust
+use substrate_fixed::types::{",
+                if_signed_unsigned! { $Signedness, "I8F8", "U0F8" },
+                "};
+",
+                if_signed_unsigned! {
+                    $Signedness,
+                    "// 0x54/256 is about 1.33 ULP below 1/3, so the error rounds to one ULP
+let third = I8F8::from_bits(0x54);
+assert_eq!(third.rational_error(1, 3), -I8F8::from_bits(1));
+assert_eq!(I8F8::from_num(0.75).rational_error(-3, -4), 0);
+",
+                    "// from_num rounds to within half an ULP of 1/3
+let third = U0F8::from_num(1.0 / 3.0);
+assert!(third.rational_error(1, 3) <= U0F8::from_bits(1));
+let above = U0F8::from_bits(third.to_bits() + 1);
+assert_eq!(above.rational_error(1, 3), U0F8::from_bits(1));
+",
+                },
+                "```
+";
+                #[inline]
+                pub fn rational_error(self, num: i64, den: i64) -> $Fixed<Frac> {
+                    assert!(den != 0, "division by zero");
+                    self.checked_rational_error(num, den).expect("overflow")
+                }
+            }
+
+            comment! {
+                "Checked rational error. Returns `self` − `num`/`den`
+rounded to the nearest, or [`None`] if `den` is zero or if the error does
+not fit.
+
+See [`rational_error`] for details.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(0.5).checked_rational_error(1, 2), Some(Fix::from_num(0)));
+assert_eq!(Fix::from_num(0.5).checked_rational_error(1, 0), None);
+",
+                if_signed_unsigned! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(0.5).checked_rational_error(3, 4), Some(Fix::from_num(-0.25)));
+",
+                    "// the error is negative
+assert_eq!(Fix::from_num(0.5).checked_rational_error(3, 4), None);
+",
+                },
+                "```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+[`rational_error`]: #method.rational_error
+";
+                #[inline]
+                pub fn checked_rational_error(self, num: i64, den: i64) -> Option<$Fixed<Frac>> {
+                    let bits = self.to_bits();
+                    let hi = if_signed_unsigned! { $Signedness, (bits as i128) >> 127, 0 };
+                    let (hi, lo) = arith::sub_rational_wide((hi, bits as u128), num, den, Frac::U32)?;
+                    let ans = lo as $Inner;
+                    let fits = if_signed_unsigned! {
+                        $Signedness,
+                        hi == (lo as i128) >> 127 && ans as i128 == lo as i128,
+                        hi == 0 && ans as u128 == lo,
+                    };
+                    if fits {
+                        Some(Self::from_bits(ans))
+                    } else {
+                        None
+                    }
+                }
+            }
+
+            if_unsigned! {
+                $Signedness;
+                comment! {
+                    "Returns the square root, rounded to the nearest.
+
+The computation uses only integer arithmetic. The square root of zero
+is zero.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(0).sqrt(), Fix::from_num(0));
+assert_eq!(Fix::from_num(9).sqrt(), Fix::from_num(3));
+// √2 = 1.0110101..., rounded to 1.0111
+assert_eq!(Fix::from_num(2).sqrt(), Fix::from_bits(0b10111));
+```
+";
+                    #[inline]
+                    pub fn sqrt(self) -> $Fixed<Frac> {
+                        let root = arith::sqrt_bits_round(self.to_bits() as u128, Frac::U32);
+                        Self::from_bits(root as $Inner)
+                    }
+                }
+            }
+
+            if_signed! {
+                $Signedness;
+                comment! {
+                    "Returns the square root, rounded to the nearest.
+
+The computation uses only integer arithmetic. The square root of zero
+is zero.
+
+# Panics
+
+Panics if the number is negative, or if the square root does not fit,
+which can only happen when there are no integer bits.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(4).sqrt(), Fix::from_num(2));
+// √2 = 1.0110101..., rounded to 1.0111
+assert_eq!(Fix::from_num(2).sqrt(), Fix::from_bits(0b10111));
+```
+";
+                    #[inline]
+                    pub fn sqrt(self) -> $Fixed<Frac> {
+                        assert!(!self.is_negative(), "square root of negative number");
+                        self.checked_sqrt().expect("overflow")
+                    }
+                }
+
+                comment! {
+                    "Checked square root. Returns the square root rounded to
+the nearest, or [`None`] if the number is negative or if the square root
+does not fit.
+
+The square root can only fail to fit when there are no integer bits.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(0.25).checked_sqrt(), Some(Fix::from_num(0.5)));
+assert_eq!(Fix::from_num(-1).checked_sqrt(), None);
+```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+";
+                    #[inline]
+                    pub fn checked_sqrt(self) -> Option<$Fixed<Frac>> {
+                        if self.is_negative() {
+                            return None;
+                        }
+                        let root = arith::sqrt_bits_round(self.to_bits() as u128, Frac::U32);
+                        if root > <$Inner>::max_value() as u128 {
+                            None
+                        } else {
+                            Some(Self::from_bits(root as $Inner))
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the reciprocal, 1 / `self`. The result is
+truncated like a division.
+
+# Panics
+
+Panics if `self` is zero.
+
+When debug assertions are enabled, this method also panics if the
+reciprocal overflows. When debug assertions are not enabled, the wrapped
+value can be returned, but it is not considered a breaking change if in
+the future it panics; if wrapping is required use [`checked_recip`]
+instead.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(0.5).recip(), Fix::from_num(2));
+assert_eq!(Fix::from_num(4).recip(), Fix::from_num(0.25));
+```
+
+[`checked_recip`]: #method.checked_recip
+";
+                #[inline]
+                pub fn recip(self) -> $Fixed<Frac> {
+                    assert!(self.to_bits() != 0, "division by zero");
+                    let (ans, overflow) = self.recip_overflow();
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Checked reciprocal. Returns 1 / `self`, or [`None`] if
+`self` is zero or on overflow.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(0.5).checked_recip(), Some(Fix::from_num(2)));
+assert_eq!(Fix::from_num(0).checked_recip(), None);
+```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+";
+                #[inline]
+                pub fn checked_recip(self) -> Option<$Fixed<Frac>> {
+                    if self.to_bits() == 0 {
+                        return None;
+                    }
+                    match self.recip_overflow() {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            #[inline]
+            fn recip_overflow(self) -> ($Fixed<Frac>, bool) {
+                let bits = self.to_bits();
+                if_signed_unsigned! {
+                    $Signedness,
+                    {
+                        let neg = bits < 0;
+                        let (quot, overflow) =
+                            arith::recip_bits(bits.unsigned_abs() as u128, Frac::U32);
+                        let max = <$Inner>::max_value() as u128 + if neg { 1 } else { 0 };
+                        let ans = if neg {
+                            (quot as $Inner).wrapping_neg()
+                        } else {
+                            quot as $Inner
+                        };
+                        (Self::from_bits(ans), overflow || quot > max)
+                    },
+                    {
+                        let (quot, overflow) = arith::recip_bits(bits as u128, Frac::U32);
+                        let max = <$Inner>::max_value() as u128;
+                        (Self::from_bits(quot as $Inner), overflow || quot > max)
+                    },
+                }
+            }
+
+            comment! {
+                "Converts the fixed-point number to a [`String`] in the given
+radix.
+
+The radix can be 2, 8, 10 or 16. In radix 2, 8 and 16, all the
+fractional bits are written out exactly, so that there are
+`FRAC_NBITS`, ⌈`FRAC_NBITS`/3⌉ or ⌈`FRAC_NBITS`/4⌉ fractional digits
+respectively, including any trailing zeros. Radix 10 gives the same output as
+[`Display`].
+
+This method is only available when the `std` feature is enabled.
+
+# Panics
+
+Panics if `radix` is not 2, 8, 10 or 16.
+
+# Examples
+
+```rust
+use substrate_fixed::types::U8F8;
+let half = U8F8::from_num(0.5);
+assert_eq!(half.to_string_radix(2), \"0.10000000\");
+assert_eq!(half.to_string_radix(8), \"0.400\");
+assert_eq!(half.to_string_radix(10), \"0.5\");
+assert_eq!(half.to_string_radix(16), \"0.80\");
+```
+
+[`Display`]: https://doc.rust-lang.org/nightly/core/fmt/trait.Display.html
+[`String`]: https://doc.rust-lang.org/nightly/std/string/struct.String.html
+";
+                #[cfg(feature = "std")]
+                #[inline]
+                pub fn to_string_radix(self, radix: u32) -> String {
+                    let frac_nbits = Frac::U32 as usize;
+                    match radix {
+                        2 => format!("{:.*b}", frac_nbits, self),
+                        8 => format!("{:.*o}", (frac_nbits + 2) / 3, self),
+                        10 => format!("{}", self),
+                        16 => format!("{:.*x}", (frac_nbits + 3) / 4, self),
+                        _ => panic!("radix {} is not supported, must be 2, 8, 10 or 16", radix),
+                    }
+                }
+            }
+
+            comment! {
+                "Quantizes `self` uniformly into 2<sup>`BITS`</sup> bins
+spanning the range [`lo`, `hi`] and returns the `BITS`-bit code of the bin
+containing `self`.
+
+Values outside the range are clamped to the first or last bin.
+
+# Panics
+
+Panics if `BITS` is not in the range 1 ≤ `BITS` ≤ 32, or if `lo` > `hi`.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let (lo, hi) = (Fix::from_num(0), Fix::from_num(4));
+// bins are 0.25 wide
+assert_eq!(Fix::from_num(0.5).to_quantized::<4>(lo, hi), 2);
+assert_eq!(Fix::from_num(0.75).to_quantized::<4>(lo, hi), 3);
+assert_eq!(Fix::from_num(4).to_quantized::<4>(lo, hi), 15);
+assert_eq!(Fix::from_num(7).to_quantized::<4>(lo, hi), 15);
+```
+";
+                #[inline]
+                pub fn to_quantized<const BITS: u32>(self, lo: $Fixed<Frac>, hi: $Fixed<Frac>) -> u32 {
+                    assert!(BITS >= 1 && BITS <= 32, "BITS must be in the range 1..=32");
+                    assert!(lo <= hi, "lo > hi");
+                    let val = if self < lo {
+                        lo
+                    } else if self > hi {
+                        hi
+                    } else {
+                        self
+                    };
+                    let range = hi.to_bits().wrapping_sub(lo.to_bits()) as $UInner as u128;
+                    let offset = val.to_bits().wrapping_sub(lo.to_bits()) as $UInner as u128;
+                    if range == 0 {
+                        return 0;
+                    }
+                    // offset <= range, so the quotient cannot overflow
+                    let (code, _) = offset.div_overflow(range, BITS);
+                    let max_code = u32::MAX >> (32 - BITS);
+                    if code > u128::from(max_code) {
+                        max_code
+                    } else {
+                        code as u32
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the center of the bin with the given `BITS`-bit
+code when the range [`lo`, `hi`] is quantized uniformly into
+2<sup>`BITS`</sup> bins.
+
+This is the inverse of [`to_quantized`]. Codes outside the range are
+clamped to the last bin.
+
+# Panics
+
+Panics if `BITS` is not in the range 1 ≤ `BITS` ≤ 32, or if `lo` > `hi`.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let (lo, hi) = (Fix::from_num(0), Fix::from_num(4));
+assert_eq!(Fix::from_quantized::<2>(0, lo, hi), Fix::from_num(0.5));
+assert_eq!(Fix::from_quantized::<2>(3, lo, hi), Fix::from_num(3.5));
+let center = Fix::from_quantized::<4>(9, lo, hi);
+assert_eq!(center.to_quantized::<4>(lo, hi), 9);
+```
+
+[`to_quantized`]: #method.to_quantized
+";
+                #[inline]
+                pub fn from_quantized<const BITS: u32>(
+                    code: u32,
+                    lo: $Fixed<Frac>,
+                    hi: $Fixed<Frac>,
+                ) -> $Fixed<Frac> {
+                    assert!(BITS >= 1 && BITS <= 32, "BITS must be in the range 1..=32");
+                    assert!(lo <= hi, "lo > hi");
+                    let max_code = u32::MAX >> (32 - BITS);
+                    let code = if code > max_code { max_code } else { code };
+                    let range = hi.to_bits().wrapping_sub(lo.to_bits()) as $UInner as u128;
+                    // range × (2 × code + 1) / 2^(BITS + 1) <= range, so it cannot overflow
+                    let (offset, _) = range.mul_overflow(u128::from(code) * 2 + 1, BITS + 1);
+                    Self::from_bits(lo.to_bits().wrapping_add(offset as $Inner))
+                }
+            }
+
             /// Remainder for division by an integer.
             ///
             /// # Panics
@@ -24,6 +24,9 @@ The other number can be:
   * An integer of type [`i8`], [`i16`], [`i32`], [`i64`], [`i128`],
     [`isize`], [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], or
     [`usize`].
+  * A [`bool`], which is converted to one or zero.
+  * A non-zero integer such as [`NonZeroU32`], which is converted
+    like the integer it holds.
   * A floating-point number of type [`f32`] or [`f64`]. If the [`f16`
     feature] is enabled, it can also be of type [`f16`] or [`bf16`].
     For this conversion, the method rounds to the nearest, with ties
@@ -69,8 +72,10 @@ assert_eq!(Fix::from_num(",
             "0b111 << (4-2)));
 ```
 
+[`NonZeroU32`]: https://doc.rust-lang.org/nightly/core/num/struct.NonZeroU32.html
 [`ToFixed`]: traits/trait.ToFixed.html
 [`bf16`]: https://docs.rs/half/^1.2/half/struct.bf16.html
+[`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
 [`f16` feature]: index.html#optional-features
 [`f16`]: https://docs.rs/half/^1.2/half/struct.f16.html
 [`f32`]: https://doc.rust-lang.org/nightly/std/primitive.f32.html
@@ -97,6 +102,335 @@ assert_eq!(Fix::from_num(",
             }
         }
 
+        comment! {
+            "Creates a fixed-point number from a [`bool`], which is
+converted to one or zero.
+
+This is the same as [`from_num`] for a [`bool`].
+
+# Panics
+
+Panics if `b` is [`true`] and one cannot be represented, that is if
+there are ",
+            if_signed_unsigned! {
+                $Signedness,
+                "less than two integer bits, as one integer bit is used for the sign",
+                "no integer bits",
+            },
+            ".
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_bool(true), Fix::from_num(1));
+assert_eq!(Fix::from_bool(false), Fix::from_num(0));
+```
+
+[`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+[`from_num`]: #method.from_num
+[`true`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+";
+            #[inline]
+            pub fn from_bool(b: bool) -> $Fixed<Frac> {
+                if !b {
+                    return Self::from_bits(0);
+                }
+                let min_int_nbits = if_signed_unsigned! { $Signedness, 2, 1 };
+                assert!(Self::INT_NBITS >= min_int_nbits, "one cannot be represented");
+                Self::from_bits(1 << Self::FRAC_NBITS)
+            }
+        }
+
+        comment! {
+            "Creates a fixed-point number from an integer part and a
+fraction, computing `int + num / den`.
+
+The fraction is rounded to the nearest, with ties rounded to even.
+Returns [`None`] if `den` is zero, if either `int` or the rounded
+`num / den` cannot be represented on its own, or if their sum overflows.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 3 + 1/4 = 3.25
+assert_eq!(Fix::from_int_frac(3, 1, 4), Some(Fix::from_num(3.25)));
+// 3 + 1/3 is rounded to 3 + 5/16
+assert_eq!(Fix::from_int_frac(3, 1, 3), Some(Fix::from_num(3.3125)));
+assert_eq!(Fix::from_int_frac(3, 1, 0), None);
+```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+";
+            #[inline]
+            pub fn from_int_frac(int: $Inner, num: $Inner, den: $Inner) -> Option<$Fixed<Frac>> {
+                if den == 0 {
+                    return None;
+                }
+                let (num_abs, den_abs) = if_signed_unsigned! {
+                    $Signedness,
+                    (num.unsigned_abs(), den.unsigned_abs()),
+                    (num, den),
+                };
+                let whole = num_abs / den_abs;
+                let rem = num_abs % den_abs;
+                // rem < den_abs, so the quotient is less than one and
+                // fits even when all the bits are fractional; the wide
+                // division handles a shift by all the bits. The new
+                // remainder is less than den_abs, so it is correct even
+                // though the shift and product below wrap.
+                let (quot, _) = rem.div_overflow(den_abs, Self::FRAC_NBITS);
+                let rem = rem
+                    .checked_shl(Self::FRAC_NBITS)
+                    .unwrap_or(0)
+                    .wrapping_sub(quot.wrapping_mul(den_abs));
+                let half = den_abs - rem;
+                let round_up = rem > half || (rem == half && quot & 1 != 0);
+                let whole_bits = if Self::FRAC_NBITS == Self::NBITS {
+                    if whole == 0 { Some(0) } else { None }
+                } else {
+                    whole.checked_mul(1 << Self::FRAC_NBITS)
+                };
+                let mut abs_bits = whole_bits?.checked_add(quot)?;
+                if round_up {
+                    abs_bits = abs_bits.checked_add(1)?;
+                }
+                let bits = if_signed_unsigned! {
+                    $Signedness,
+                    {
+                        let neg = (num < 0) != (den < 0);
+                        let bits = if neg {
+                            (abs_bits as $Inner).wrapping_neg()
+                        } else {
+                            abs_bits as $Inner
+                        };
+                        if abs_bits != 0 && (bits < 0) != neg {
+                            return None;
+                        }
+                        bits
+                    },
+                    abs_bits,
+                };
+                Self::checked_from_num(int)?.checked_add(Self::from_bits(bits))
+            }
+        }
+
+        comment! {
+            "Creates a fixed-point number of seconds from a [`Duration`].
+
+The result is rounded to the nearest, with ties rounded to even. For
+types with more than 64 fractional bits, the fraction of a second is
+only computed to 64 bits. Returns [`None`] if the value does not fit.
+
+# Examples
+
+```rust
+use core::time::Duration;
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let d = Duration::from_millis(2500);
+assert_eq!(Fix::from_duration_secs(d), Some(Fix::from_num(2.5)));
+```
+
+[`Duration`]: https://doc.rust-lang.org/nightly/core/time/struct.Duration.html
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+";
+            #[inline]
+            pub fn from_duration_secs(d: Duration) -> Option<$Fixed<Frac>> {
+                const NANOS_PER_SEC: u128 = 1_000_000_000;
+                let nanos = u128::from(d.subsec_nanos()) << 64;
+                let mut frac = nanos / NANOS_PER_SEC;
+                // make the lowest bit sticky so that rounding to fewer
+                // bits cannot see a false tie
+                if nanos % NANOS_PER_SEC != 0 {
+                    frac |= 1;
+                }
+                let secs = U64F64::from_bits(u128::from(d.as_secs()) << 64 | frac);
+                let (wrapped, overflow) = secs.private_overflowing_to_fixed_round(Round::Nearest);
+                if overflow {
+                    None
+                } else {
+                    Some(wrapped)
+                }
+            }
+        }
+
+        comment! {
+            "Converts a fixed-point number of seconds to a [`Duration`].
+
+The fractional part is rounded to the nearest nanosecond, with ties
+rounded up.
+
+# Panics
+
+Panics if the value is negative or if it is too large for a
+[`Duration`].
+
+# Examples
+
+```rust
+use core::time::Duration;
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2.5).to_duration_secs(), Duration::from_millis(2500));
+```
+
+[`Duration`]: https://doc.rust-lang.org/nightly/core/time/struct.Duration.html
+";
+            #[inline]
+            pub fn to_duration_secs(self) -> Duration {
+                const NANOS_PER_SEC: u128 = 1_000_000_000;
+                let secs = match U64F64::checked_from_num(self) {
+                    Some(secs) => secs.to_bits(),
+                    None => panic!("duration out of range"),
+                };
+                let frac = u128::from(secs as u64);
+                let nanos = (frac * NANOS_PER_SEC + (1 << 63)) >> 64;
+                let whole = Duration::from_secs((secs >> 64) as u64);
+                whole
+                    .checked_add(Duration::from_nanos(nanos as u64))
+                    .expect("duration out of range")
+            }
+        }
+
+        comment! {
+            "Creates a fixed-point number from an [`f16`].
+
+This is the same as [`from_num`] for an [`f16`] source. The value is
+rounded to the nearest, with ties rounded to even.
+
+This method is only available with the [`f16` feature].
+
+# Panics
+
+Panics if the value is not [finite].
+
+When debug assertions are enabled, also panics if the value does not
+fit. When debug assertions are not enabled, the wrapped value can be
+returned.
+
+# Examples
+
+```rust
+use half::f16;
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_f16(f16::from_f32(1.75)), Fix::from_num(1.75));
+```
+
+[`f16` feature]: index.html#optional-features
+[`from_num`]: #method.from_num
+[finite]: https://doc.rust-lang.org/nightly/std/primitive.f64.html#method.is_finite
+[`f16`]: https://docs.rs/half/^1.2/half/struct.f16.html
+";
+            #[cfg(feature = "f16")]
+            #[inline]
+            pub fn from_f16(src: f16) -> $Fixed<Frac> {
+                Self::from_num(src)
+            }
+        }
+
+        comment! {
+            "Creates a fixed-point number from an [`f16`] if it fits,
+otherwise returns [`None`].
+
+This is the same as [`checked_from_num`] for an [`f16`] source. NaN
+and infinities give [`None`].
+
+This method is only available with the [`f16` feature].
+
+# Examples
+
+```rust
+use half::f16;
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::checked_from_f16(f16::from_f32(1.75)), Some(Fix::from_num(1.75)));
+assert_eq!(Fix::checked_from_f16(f16::NAN), None);
+assert_eq!(Fix::checked_from_f16(f16::INFINITY), None);
+```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+[`checked_from_num`]: #method.checked_from_num
+[`f16` feature]: index.html#optional-features
+[`f16`]: https://docs.rs/half/^1.2/half/struct.f16.html
+";
+            #[cfg(feature = "f16")]
+            #[inline]
+            pub fn checked_from_f16(src: f16) -> Option<$Fixed<Frac>> {
+                Self::checked_from_num(src)
+            }
+        }
+
+        comment! {
+            "Creates a fixed-point number from an [`f16`], saturating if
+it does not fit.
+
+This is the same as [`saturating_from_num`] for an [`f16`] source.
+Infinities saturate to the minimum or maximum value.
+
+This method is only available with the [`f16` feature].
+
+# Panics
+
+Panics if the value is NaN.
+
+# Examples
+
+```rust
+use half::f16;
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::saturating_from_f16(f16::from_f32(1.75)), Fix::from_num(1.75));
+assert_eq!(Fix::saturating_from_f16(f16::INFINITY), Fix::max_value());
+```
+
+[`f16` feature]: index.html#optional-features
+[`saturating_from_num`]: #method.saturating_from_num
+[`f16`]: https://docs.rs/half/^1.2/half/struct.f16.html
+";
+            #[cfg(feature = "f16")]
+            #[inline]
+            pub fn saturating_from_f16(src: f16) -> $Fixed<Frac> {
+                Self::saturating_from_num(src)
+            }
+        }
+
+        comment! {
+            "Converts a fixed-point number to an [`f16`].
+
+This is the same as [`to_num`] with an [`f16`] destination.
+
+An [`f16`] has an 11-bit significand, so only the 11 most significant
+bits of the fixed-point number are kept, rounding to the nearest with
+ties rounded to even. Values with a magnitude of 65520 or more
+become infinite.
+
+This method is only available with the [`f16` feature].
+
+# Examples
+
+```rust
+use half::f16;
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(1.75).to_f16(), f16::from_f32(1.75));
+```
+
+[`f16` feature]: index.html#optional-features
+[`to_num`]: #method.to_num
+[`f16`]: https://docs.rs/half/^1.2/half/struct.f16.html
+";
+            #[cfg(feature = "f16")]
+            #[inline]
+            pub fn to_f16(self) -> f16 {
+                self.to_num()
+            }
+        }
+
         comment! {
             "Converts a fixed-point number to another number.
 
@@ -193,10 +527,14 @@ The other number can be:
   * An integer of type [`i8`], [`i16`], [`i32`], [`i64`], [`i128`],
     [`isize`], [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], or
     [`usize`].
+  * A [`bool`], which is converted to one or zero.
+  * A non-zero integer such as [`NonZeroU32`], which is converted
+    like the integer it holds.
   * A floating-point number of type [`f32`] or [`f64`]. If the [`f16`
     feature] is enabled, it can also be of type [`f16`] or [`bf16`].
     For this conversion, the method rounds to the nearest, with ties
-    rounding to even.
+    rounding to even. Non-finite values, that is NaN and infinities,
+    give [`None`].
   * Any other number `src` for which [`ToFixed`] is implemented, in
     which case this method returns [`src.checked_to_fixed()`][`checked_to_fixed`].
 
@@ -243,8 +581,10 @@ assert!(Fix::checked_from_num(std::f64::NAN).is_none());
 ```
 
 [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+[`NonZeroU32`]: https://doc.rust-lang.org/nightly/core/num/struct.NonZeroU32.html
 [`ToFixed`]: traits/trait.ToFixed.html
 [`bf16`]: https://docs.rs/half/^1.2/half/struct.bf16.html
+[`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
 [`f16` feature]: index.html#optional-features
 [`f16`]: https://docs.rs/half/^1.2/half/struct.f16.html
 [`f32`]: https://doc.rust-lang.org/nightly/std/primitive.f32.html
@@ -269,6 +609,51 @@ assert!(Fix::checked_from_num(std::f64::NAN).is_none());
             }
         }
 
+        comment! {
+            "Creates a fixed-point number from another number if it
+fits, otherwise returns an error saying whether the value was too
+large or too small.
+
+The other number can be any number for which [`ToFixed`] is
+implemented, and it is converted as in [`checked_from_num`]. The
+error is [`Underflow`] if the value is smaller than the minimum,
+which includes negative values for unsigned fixed-point numbers, and
+[`Overflow`] if the value is larger than the maximum.
+
+# Panics
+
+Panics if the other number is a floating-point [NaN].
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, ", FromNumError};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num_checked_detail(3), Ok(Fix::from_num(3)));
+assert_eq!(Fix::from_num_checked_detail(u128::max_value()), Err(FromNumError::Overflow));
+assert_eq!(Fix::from_num_checked_detail(i128::min_value()), Err(FromNumError::Underflow));
+```
+
+[NaN]: https://doc.rust-lang.org/nightly/std/primitive.f64.html#method.is_nan
+[`Overflow`]: enum.FromNumError.html#variant.Overflow
+[`ToFixed`]: traits/trait.ToFixed.html
+[`Underflow`]: enum.FromNumError.html#variant.Underflow
+[`checked_from_num`]: #method.checked_from_num
+";
+            #[inline]
+            pub fn from_num_checked_detail<Src: ToFixed + Copy>(
+                src: Src,
+            ) -> Result<$Fixed<Frac>, FromNumError> {
+                match src.checked_to_fixed() {
+                    Some(fixed) => Ok(fixed),
+                    None if src.saturating_to_fixed::<Self>() == Self::min_value() => {
+                        Err(FromNumError::Underflow)
+                    }
+                    None => Err(FromNumError::Overflow),
+                }
+            }
+        }
+
         comment! {
             "Converts a fixed-point number to another number if it
 fits, otherwise returns [`None`].
@@ -365,6 +750,9 @@ The other number can be:
   * An integer of type [`i8`], [`i16`], [`i32`], [`i64`], [`i128`],
     [`isize`], [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], or
     [`usize`].
+  * A [`bool`], which is converted to one or zero.
+  * A non-zero integer such as [`NonZeroU32`], which is converted
+    like the integer it holds.
   * A floating-point number of type [`f32`] or [`f64`]. If the [`f16`
     feature] is enabled, it can also be of type [`f16`] or [`bf16`].
     For this conversion, the method rounds to the nearest, with ties
@@ -418,8 +806,10 @@ assert_eq!(Fix::saturating_from_num(std::f64::NEG_INFINITY), Fix::min_value());
 ```
 
 [NaN]: https://doc.rust-lang.org/nightly/std/primitive.f64.html#method.is_nan
+[`NonZeroU32`]: https://doc.rust-lang.org/nightly/core/num/struct.NonZeroU32.html
 [`ToFixed`]: traits/trait.ToFixed.html
 [`bf16`]: https://docs.rs/half/^1.2/half/struct.bf16.html
+[`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
 [`f16` feature]: index.html#optional-features
 [`f16`]: https://docs.rs/half/^1.2/half/struct.f16.html
 [`f32`]: https://doc.rust-lang.org/nightly/std/primitive.f32.html
@@ -536,6 +926,9 @@ The other number can be:
   * An integer of type [`i8`], [`i16`], [`i32`], [`i64`], [`i128`],
     [`isize`], [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], or
     [`usize`].
+  * A [`bool`], which is converted to one or zero.
+  * A non-zero integer such as [`NonZeroU32`], which is converted
+    like the integer it holds.
   * A floating-point number of type [`f32`] or [`f64`]. If the [`f16`
     feature] is enabled, it can also be of type [`f16`] or [`bf16`].
     For this conversion, the method rounds to the nearest, with ties
@@ -579,8 +972,10 @@ let wrapped = Fix::from_bits(0b1100 << (", $s_nbits, " - 4));
 assert_eq!(Fix::wrapping_from_num(large), wrapped);
 ```
 
+[`NonZeroU32`]: https://doc.rust-lang.org/nightly/core/num/struct.NonZeroU32.html
 [`ToFixed`]: traits/trait.ToFixed.html
 [`bf16`]: https://docs.rs/half/^1.2/half/struct.bf16.html
+[`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
 [`f16` feature]: index.html#optional-features
 [`f16`]: https://docs.rs/half/^1.2/half/struct.f16.html
 [`f32`]: https://doc.rust-lang.org/nightly/std/primitive.f32.html
@@ -701,6 +1096,9 @@ The other number can be:
   * An integer of type [`i8`], [`i16`], [`i32`], [`i64`], [`i128`],
     [`isize`], [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], or
     [`usize`].
+  * A [`bool`], which is converted to one or zero.
+  * A non-zero integer such as [`NonZeroU32`], which is converted
+    like the integer it holds.
   * A floating-point number of type [`f32`] or [`f64`]. If the [`f16`
     feature] is enabled, it can also be of type [`f16`] or [`bf16`]. For this
     conversion, the method rounds to the nearest, with ties rounding
@@ -746,6 +1144,7 @@ let wrapped = Fix::from_bits(0b1100 << (", $s_nbits, " - 4));
 assert_eq!(Fix::overflowing_from_num(large), (wrapped, true));
 ```
 
+[`NonZeroU32`]: https://doc.rust-lang.org/nightly/core/num/struct.NonZeroU32.html
 [`ToFixed`]: traits/trait.ToFixed.html
 [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
 [`bf16`]: https://docs.rs/half/^1.2/half/struct.bf16.html
@@ -863,6 +1262,564 @@ assert_eq!(one_point_625.overflowing_to_num::<f32>(), (1.625f32, false));
             }
         }
 
+        comment! {
+            "Converts the fixed-point number to [`f32`] if the
+conversion is exact.
+
+If the value needs more than 24 significant bits, the conversion
+would round, and an [`Inexact`] error holding the rounded value is
+returned instead.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(0.5).to_f32_exact(), Ok(0.5));
+assert_eq!(Fix::from_bits(1).to_f32_exact(), Ok(0.0625));
+```
+
+[`Inexact`]: struct.Inexact.html
+[`f32`]: https://doc.rust-lang.org/nightly/std/primitive.f32.html
+";
+            #[inline]
+            pub fn to_f32_exact(self) -> Result<f32, Inexact<f32>> {
+                let rounded: f32 = self.to_num();
+                if Self::checked_from_num(rounded) == Some(self) {
+                    Ok(rounded)
+                } else {
+                    Err(Inexact::new(rounded))
+                }
+            }
+        }
+
+        comment! {
+            "Converts the fixed-point number to [`f64`] if the
+conversion is exact.
+
+If the value needs more than 53 significant bits, the conversion
+would round, and an [`Inexact`] error holding the rounded value is
+returned instead.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(0.5).to_f64_exact(), Ok(0.5));
+assert_eq!(Fix::from_bits(1).to_f64_exact(), Ok(0.0625));
+```
+
+[`Inexact`]: struct.Inexact.html
+[`f64`]: https://doc.rust-lang.org/nightly/std/primitive.f64.html
+";
+            #[inline]
+            pub fn to_f64_exact(self) -> Result<f64, Inexact<f64>> {
+                let rounded: f64 = self.to_num();
+                if Self::checked_from_num(rounded) == Some(self) {
+                    Ok(rounded)
+                } else {
+                    Err(Inexact::new(rounded))
+                }
+            }
+        }
+
+        comment! {
+            "Converts a fixed-point number to another number, and
+returns how the fixed-point number compares to the result.
+
+The conversion is done as in [`to_num`]; for integers any fractional
+bits are truncated, so a value with a non-zero fractional part
+compares as [`Greater`] than the result. The [`Ordering`] is
+[`Equal`] only if the conversion was exact.
+
+# Panics
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, and the [`Ordering`] compares with the wrapped value.
+
+# Examples
+
+```rust
+use core::cmp::Ordering;
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2.5).to_num_round::<i32>(), (2, Ordering::Greater));
+assert_eq!(Fix::from_num(3).to_num_round::<u8>(), (3, Ordering::Equal));
+assert_eq!(Fix::from_num(2.5).to_num_round::<f32>(), (2.5, Ordering::Equal));
+```
+
+[`Equal`]: https://doc.rust-lang.org/nightly/core/cmp/enum.Ordering.html#variant.Equal
+[`Greater`]: https://doc.rust-lang.org/nightly/core/cmp/enum.Ordering.html#variant.Greater
+[`Ordering`]: https://doc.rust-lang.org/nightly/core/cmp/enum.Ordering.html
+[`to_num`]: #method.to_num
+";
+            #[inline]
+            pub fn to_num_round<Dst: FromFixed>(self) -> (Dst, Ordering)
+            where
+                Self: PartialOrd<Dst>,
+            {
+                let dst = Dst::from_fixed(self);
+                let ord = self.partial_cmp(&dst).expect("not a number");
+                (dst, ord)
+            }
+        }
+
+        comment! {
+            "Creates a fixed-point number from another number, rounding
+towards −∞.
+
+The other number can be any number for which [`ToFixed`] is
+implemented. Rounding only has an effect when the other number is a
+floating-point number or a fixed-point number with more fractional
+bits than `Self`; integers are always converted exactly.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+When debug assertions are enabled, panics if the rounded value does
+not fit. When debug assertions are not enabled, the wrapped value can
+be returned.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 1/32 is smaller than the delta 1/16
+assert_eq!(Fix::from_num_floor(2.03125), Fix::from_num(2));
+assert_eq!(Fix::from_num_floor(2.5), Fix::from_num(2.5));
+",
+            if_signed_else_empty_str! {
+                $Signedness,
+                "assert_eq!(Fix::from_num_floor(-2.03125), Fix::from_num(-2.0625));
+",
+            },
+            "```
+
+[`ToFixed`]: traits/trait.ToFixed.html
+[finite]: https://doc.rust-lang.org/nightly/std/primitive.f64.html#method.is_finite
+";
+            #[inline]
+            pub fn from_num_floor<Src: ToFixed>(src: Src) -> $Fixed<Frac> {
+                let (wrapped, overflow) = src.private_overflowing_to_fixed_round(Round::Floor);
+                debug_assert!(!overflow, "overflow");
+                wrapped
+            }
+        }
+
+        comment! {
+            "Creates a fixed-point number from another number, rounding
+towards +∞.
+
+The other number can be any number for which [`ToFixed`] is
+implemented. Rounding only has an effect when the other number is a
+floating-point number or a fixed-point number with more fractional
+bits than `Self`; integers are always converted exactly.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+When debug assertions are enabled, panics if the rounded value does
+not fit. When debug assertions are not enabled, the wrapped value can
+be returned.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 1/32 is smaller than the delta 1/16
+assert_eq!(Fix::from_num_ceil(2.03125), Fix::from_num(2.0625));
+assert_eq!(Fix::from_num_ceil(2.5), Fix::from_num(2.5));
+",
+            if_signed_else_empty_str! {
+                $Signedness,
+                "assert_eq!(Fix::from_num_ceil(-2.03125), Fix::from_num(-2));
+",
+            },
+            "```
+
+[`ToFixed`]: traits/trait.ToFixed.html
+[finite]: https://doc.rust-lang.org/nightly/std/primitive.f64.html#method.is_finite
+";
+            #[inline]
+            pub fn from_num_ceil<Src: ToFixed>(src: Src) -> $Fixed<Frac> {
+                let (wrapped, overflow) = src.private_overflowing_to_fixed_round(Round::Ceil);
+                debug_assert!(!overflow, "overflow");
+                wrapped
+            }
+        }
+
+        comment! {
+            "Creates a fixed-point number from another number, rounding
+towards zero.
+
+The other number can be any number for which [`ToFixed`] is
+implemented. Rounding only has an effect when the other number is a
+floating-point number or a fixed-point number with more fractional
+bits than `Self`; integers are always converted exactly.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+When debug assertions are enabled, panics if the rounded value does
+not fit. When debug assertions are not enabled, the wrapped value can
+be returned.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 1/32 is smaller than the delta 1/16
+assert_eq!(Fix::from_num_trunc(2.03125), Fix::from_num(2));
+",
+            if_signed_else_empty_str! {
+                $Signedness,
+                "assert_eq!(Fix::from_num_trunc(-2.03125), Fix::from_num(-2));
+",
+            },
+            "```
+
+[`ToFixed`]: traits/trait.ToFixed.html
+[finite]: https://doc.rust-lang.org/nightly/std/primitive.f64.html#method.is_finite
+";
+            #[inline]
+            pub fn from_num_trunc<Src: ToFixed>(src: Src) -> $Fixed<Frac> {
+                let (wrapped, overflow) = src.private_overflowing_to_fixed_round(Round::Trunc);
+                debug_assert!(!overflow, "overflow");
+                wrapped
+            }
+        }
+
+        comment! {
+            "Creates a fixed-point number from another number, rounding
+to the nearest, with ties rounded to even.
+
+The other number can be any number for which [`ToFixed`] is
+implemented. Rounding only has an effect when the other number is a
+floating-point number or a fixed-point number with more fractional
+bits than `Self`; integers are always converted exactly.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+When debug assertions are enabled, panics if the rounded value does
+not fit. When debug assertions are not enabled, the wrapped value can
+be returned.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 1/32 is half the delta 1/16
+assert_eq!(Fix::from_num_round_ties_even(2.03125), Fix::from_num(2));
+assert_eq!(Fix::from_num_round_ties_even(2.09375), Fix::from_num(2.125));
+",
+            if_signed_else_empty_str! {
+                $Signedness,
+                "assert_eq!(Fix::from_num_round_ties_even(-2.09375), Fix::from_num(-2.125));
+",
+            },
+            "```
+
+[`ToFixed`]: traits/trait.ToFixed.html
+[finite]: https://doc.rust-lang.org/nightly/std/primitive.f64.html#method.is_finite
+";
+            #[inline]
+            pub fn from_num_round_ties_even<Src: ToFixed>(src: Src) -> $Fixed<Frac> {
+                let (wrapped, overflow) = src.private_overflowing_to_fixed_round(Round::Nearest);
+                debug_assert!(!overflow, "overflow");
+                wrapped
+            }
+        }
+
+        comment! {
+            "Converts a fixed-point number to another number, rounding
+towards −∞.
+
+The other number can be an integer or another fixed-point number, in
+which case the rounding only has an effect when it has fewer
+fractional bits than `Self`. For a floating-point number, or any
+other number for which [`FromFixed`] is implemented without rounding
+support, the result is the same as for [`to_num`].
+
+# Panics
+
+When debug assertions are enabled, panics if the rounded value does
+not fit. When debug assertions are not enabled, the wrapped value can
+be returned.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2.5).to_num_floor::<i32>(), 2);
+",
+            if_signed_else_empty_str! {
+                $Signedness,
+                "assert_eq!(Fix::from_num(-2.5).to_num_floor::<i32>(), -3);
+",
+            },
+            "```
+
+[`FromFixed`]: traits/trait.FromFixed.html
+[`to_num`]: #method.to_num
+";
+            #[inline]
+            pub fn to_num_floor<Dst: FromFixed>(self) -> Dst {
+                let (wrapped, overflow) =
+                    Dst::private_overflowing_from_fixed_round(self, Round::Floor);
+                debug_assert!(!overflow, "overflow");
+                wrapped
+            }
+        }
+
+        comment! {
+            "Converts a fixed-point number to another number, rounding
+towards +∞.
+
+The other number can be an integer or another fixed-point number, in
+which case the rounding only has an effect when it has fewer
+fractional bits than `Self`. For a floating-point number, or any
+other number for which [`FromFixed`] is implemented without rounding
+support, the result is the same as for [`to_num`].
+
+# Panics
+
+When debug assertions are enabled, panics if the rounded value does
+not fit. When debug assertions are not enabled, the wrapped value can
+be returned.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2.5).to_num_ceil::<i32>(), 3);
+",
+            if_signed_else_empty_str! {
+                $Signedness,
+                "assert_eq!(Fix::from_num(-2.5).to_num_ceil::<i32>(), -2);
+",
+            },
+            "```
+
+[`FromFixed`]: traits/trait.FromFixed.html
+[`to_num`]: #method.to_num
+";
+            #[inline]
+            pub fn to_num_ceil<Dst: FromFixed>(self) -> Dst {
+                let (wrapped, overflow) =
+                    Dst::private_overflowing_from_fixed_round(self, Round::Ceil);
+                debug_assert!(!overflow, "overflow");
+                wrapped
+            }
+        }
+
+        comment! {
+            "Converts a fixed-point number to another number, rounding
+towards zero.
+
+The other number can be an integer or another fixed-point number, in
+which case the rounding only has an effect when it has fewer
+fractional bits than `Self`. For a floating-point number, or any
+other number for which [`FromFixed`] is implemented without rounding
+support, the result is the same as for [`to_num`].
+
+# Panics
+
+When debug assertions are enabled, panics if the rounded value does
+not fit. When debug assertions are not enabled, the wrapped value can
+be returned.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2.5).to_num_trunc::<i32>(), 2);
+",
+            if_signed_else_empty_str! {
+                $Signedness,
+                "assert_eq!(Fix::from_num(-2.5).to_num_trunc::<i32>(), -2);
+",
+            },
+            "```
+
+[`FromFixed`]: traits/trait.FromFixed.html
+[`to_num`]: #method.to_num
+";
+            #[inline]
+            pub fn to_num_trunc<Dst: FromFixed>(self) -> Dst {
+                let (wrapped, overflow) =
+                    Dst::private_overflowing_from_fixed_round(self, Round::Trunc);
+                debug_assert!(!overflow, "overflow");
+                wrapped
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing digits in the given radix to return a
+fixed-point number.
+
+The radix can be 2, 8, 10 or 16. Rounding is to the nearest, with ties
+rounded to even.
+
+# Panics
+
+Panics if `radix` is not 2, 8, 10 or 16.
+
+# Examples
+
+```rust
+",
+            if_signed_unsigned! {
+                $Signedness,
+                "use substrate_fixed::types::I16F16;
+assert_eq!(I16F16::from_str_radix(\"ff.8\", 16), Ok(I16F16::from_num(255.5)));
+assert_eq!(I16F16::from_str_radix(\"-ff.8\", 16), Ok(I16F16::from_num(-255.5)));
+",
+                "use substrate_fixed::types::U16F16;
+assert_eq!(U16F16::from_str_radix(\"ff.8\", 16), Ok(U16F16::from_num(255.5)));
+assert_eq!(U16F16::from_str_radix(\"11.1\", 2), Ok(U16F16::from_num(3.5)));
+",
+            },
+            "```
+";
+            #[inline]
+            pub fn from_str_radix(src: &str, radix: u32) -> Result<$Fixed<Frac>, ParseFixedError> {
+                match radix {
+                    2 | 8 | 10 | 16 => FromStrRadix::from_str_radix(src, radix),
+                    _ => panic!("radix {} is not supported, must be 2, 8, 10 or 16", radix),
+                }
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing digits in the given radix to return a
+fixed-point number, returning [`None`] on failure.
+
+Unlike [`from_str_radix`], this method does not panic if the radix is not
+2, 8, 10 or 16, and it intentionally discards the reason for the failure;
+use [`from_str_radix`] if a [`ParseFixedError`] is needed. Rounding is to
+the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::checked_from_str_radix(\"1.1\", 2), Some(Fix::from_num(1.5)));
+assert_eq!(Fix::checked_from_str_radix(\"1.2\", 2), None);
+assert_eq!(Fix::checked_from_str_radix(\"1.5\", 7), None);
+```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+[`ParseFixedError`]: struct.ParseFixedError.html
+[`from_str_radix`]: #method.from_str_radix
+";
+            #[inline]
+            pub fn checked_from_str_radix(src: &str, radix: u32) -> Option<$Fixed<Frac>> {
+                match radix {
+                    2 | 8 | 10 | 16 => FromStrRadix::from_str_radix(src, radix).ok(),
+                    _ => None,
+                }
+            }
+        }
+
+        comment! {
+            "Parses the longest prefix of a string slice that contains a
+decimal number, and returns the fixed-point number together with the
+length in bytes of the prefix.
+
+The prefix has an optional sign, followed by digits with an optional
+point. Rounding is to the nearest, with ties rounded to even. An error of
+kind [`NoDigits`] is returned if the prefix has no digits.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, ", ParseErrorKind};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_str_prefix(\"1.5abc\"), Ok((Fix::from_num(1.5), 3)));
+assert_eq!(Fix::from_str_prefix(\"2,3\"), Ok((Fix::from_num(2), 1)));
+let err = Fix::from_str_prefix(\"abc\").unwrap_err();
+assert_eq!(err.kind(), ParseErrorKind::NoDigits);
+```
+
+[`NoDigits`]: enum.ParseErrorKind.html#variant.NoDigits
+";
+            #[inline]
+            pub fn from_str_prefix(src: &str) -> Result<($Fixed<Frac>, usize), ParseFixedError> {
+                from_str::from_str_prefix(src, 10)
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing decimal digits to return a
+fixed-point number, ignoring leading and trailing ASCII whitespace.
+
+Whitespace inside the number is still an error. Rounding is to the
+nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_str_trimmed(\" 3.25 \"), Ok(Fix::from_num(3.25)));
+assert_eq!(Fix::from_str_trimmed(\"\\t3.25\\r\\n\"), Ok(Fix::from_num(3.25)));
+assert!(Fix::from_str_trimmed(\"3 .25\").is_err());
+assert!(Fix::from_str_trimmed(\" \\n \").is_err());
+```
+";
+            #[inline]
+            pub fn from_str_trimmed(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
+                from_str::from_str_trimmed(src)
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing a decimal percentage
+followed by `%` to return a fixed-point number.
+
+The percentage is divided by 100, so `\"12.5%\"` gives 0.125.
+Rounding is to the nearest, with ties rounded to even, and is done only
+once, on the exact quotient.
+
+The `%` is required: if it is missing, an error of kind
+[`InvalidDigit`] is returned. An error of kind [`Overflow`] is returned
+if the value does not fit.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, ", ParseErrorKind};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_str_percent(\"250%\"), Ok(Fix::from_num(2.5)));
+assert_eq!(Fix::from_str_percent(\"12.5%\"), Ok(Fix::from_num(0.125)));
+let err = Fix::from_str_percent(\"12.5\").unwrap_err();
+assert_eq!(err.kind(), ParseErrorKind::InvalidDigit);
+```
+
+[`InvalidDigit`]: enum.ParseErrorKind.html#variant.InvalidDigit
+[`Overflow`]: enum.ParseErrorKind.html#variant.Overflow
+";
+            #[inline]
+            pub fn from_str_percent(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
+                from_str::from_str_percent(src)
+            }
+        }
+
         comment! {
             "Parses a string slice containing binary digits to return a fixed-point number.
 
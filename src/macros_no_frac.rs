@@ -18,9 +18,54 @@ macro_rules! fixed_no_frac {
         $description:expr,
         $Fixed:ident[$s_fixed:expr]($Inner:ty[$s_inner:expr], $s_nbits:expr),
         $nbytes:expr, $bytes_val:expr, $be_bytes:expr, $le_bytes:expr,
-        $UInner:ty, $Signedness:tt
+        $UFixed:ident($UInner:ty), $Signedness:tt
     ) => {
         impl<Frac> $Fixed<Frac> {
+            comment! {
+                "The smallest value that can be represented.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::MIN, Fix::from_bits(", $s_inner, "::MIN));
+```
+";
+                pub const MIN: $Fixed<Frac> = Self::from_bits(<$Inner>::MIN);
+            }
+
+            comment! {
+                "The largest value that can be represented.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::MAX, Fix::from_bits(", $s_inner, "::MAX));
+```
+";
+                pub const MAX: $Fixed<Frac> = Self::from_bits(<$Inner>::MAX);
+            }
+
+            comment! {
+                "The difference between any two successive representable
+numbers, that is the value with only the least significant bit set.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::DELTA, Fix::from_bits(1));
+// binary 0.0001 is decimal 0.0625
+assert_eq!(Fix::DELTA, 0.0625);
+```
+";
+                pub const DELTA: $Fixed<Frac> = Self::from_bits(1);
+            }
+
             comment! {
                 "Returns the smallest value that can be represented.
 
@@ -96,6 +141,100 @@ assert_eq!(Fix::from_num(2).to_bits(), 0b10_0000);
                 }
             }
 
+            comment! {
+                "Creates a fixed-point number that has a bitwise
+representation identical to the given unsigned integer.
+
+This reinterprets the bits, it does not convert the value",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    ", so a set most significant bit gives a negative number",
+                },
+                if_unsigned_else_empty_str! {
+                    $Signedness,
+                    ", so for this type it is the same as [`from_bits`]",
+                },
+                ".
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+",
+                if_signed_unsigned! {
+                    $Signedness,
+                    concat!(
+                        "// −1 is 1111…1111.0000 in two’s complement
+let bits = ", stringify!($UInner), "::max_value() << 4;
+assert_eq!(Fix::from_bits_unsigned(bits), -1);
+",
+                    ),
+                    "// 0010.0000 == 2
+assert_eq!(Fix::from_bits_unsigned(0b10_0000), 2);
+",
+                },
+                "```
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness,
+                    "
+[`from_bits`]: #method.from_bits
+",
+                };
+                #[inline]
+                pub const fn from_bits_unsigned(bits: $UInner) -> $Fixed<Frac> {
+                    Self::from_bits(if_signed_unsigned! { $Signedness, bits as $Inner, bits })
+                }
+            }
+
+            comment! {
+                "Creates an unsigned integer that has a bitwise
+representation identical to the given fixed-point number.
+
+This reinterprets the bits, it does not convert the value",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    ", so a negative number gives a set most significant bit",
+                },
+                if_unsigned_else_empty_str! {
+                    $Signedness,
+                    ", so for this type it is the same as [`to_bits`]",
+                },
+                ".
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+",
+                if_signed_unsigned! {
+                    $Signedness,
+                    concat!(
+                        "// −1 is 1111…1111.0000 in two’s complement
+let bits = ", stringify!($UInner), "::max_value() << 4;
+assert_eq!(Fix::from_num(-1).to_bits_unsigned(), bits);
+",
+                    ),
+                    "// 2 is 0010.0000
+assert_eq!(Fix::from_num(2).to_bits_unsigned(), 0b10_0000);
+",
+                },
+                "```
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness,
+                    "
+[`to_bits`]: #method.to_bits
+",
+                };
+                #[inline]
+                pub const fn to_bits_unsigned(self) -> $UInner {
+                    if_signed_unsigned! { $Signedness, self.to_bits() as $UInner, self.to_bits() }
+                }
+            }
+
             comment! {
                 "Creates a fixed-point number from its representation
 as a byte array in big endian.
@@ -112,7 +251,7 @@ assert_eq!(
 ```
 ";
                 #[inline]
-                pub fn from_be_bytes(bytes: [u8; $nbytes]) -> $Fixed<Frac> {
+                pub const fn from_be_bytes(bytes: [u8; $nbytes]) -> $Fixed<Frac> {
                     $Fixed::from_bits(<$Inner>::from_be_bytes(bytes))
                 }
             }
@@ -133,7 +272,7 @@ assert_eq!(
 ```
 ";
                 #[inline]
-                pub fn from_le_bytes(bytes: [u8; $nbytes]) -> $Fixed<Frac> {
+                pub const fn from_le_bytes(bytes: [u8; $nbytes]) -> $Fixed<Frac> {
                     $Fixed::from_bits(<$Inner>::from_le_bytes(bytes))
                 }
             }
@@ -158,7 +297,7 @@ assert_eq!(
 ```
 ";
                 #[inline]
-                pub fn from_ne_bytes(bytes: [u8; $nbytes]) -> $Fixed<Frac> {
+                pub const fn from_ne_bytes(bytes: [u8; $nbytes]) -> $Fixed<Frac> {
                     $Fixed::from_bits(<$Inner>::from_ne_bytes(bytes))
                 }
             }
@@ -180,7 +319,7 @@ assert_eq!(
 ```
 ";
                 #[inline]
-                pub fn to_be_bytes(self) -> [u8; $nbytes] {
+                pub const fn to_be_bytes(self) -> [u8; $nbytes] {
                     self.to_bits().to_be_bytes()
                 }
             }
@@ -202,7 +341,7 @@ assert_eq!(
 ```
 ";
                 #[inline]
-                pub fn to_le_bytes(self) -> [u8; $nbytes] {
+                pub const fn to_le_bytes(self) -> [u8; $nbytes] {
                     self.to_bits().to_le_bytes()
                 }
             }
@@ -228,7 +367,7 @@ assert_eq!(
 ```
 ";
                 #[inline]
-                pub fn to_ne_bytes(self) -> [u8; $nbytes] {
+                pub const fn to_ne_bytes(self) -> [u8; $nbytes] {
                     self.to_bits().to_ne_bytes()
                 }
             }
@@ -236,7 +375,15 @@ assert_eq!(
             comment! {
                 "Returns the number of ones in the binary
 representation.
-
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "
+For a negative number, the ones of its two’s-complement
+representation are counted.
+",
+                },
+                "
 # Examples
 
 ```rust
@@ -254,7 +401,15 @@ assert_eq!(f.count_ones(), 3);
             comment! {
                 "Returns the number of zeros in the binary
 representation.
-
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "
+For a negative number, the zeros of its two’s-complement
+representation are counted.
+",
+                },
+                "
 # Examples
 
 ```rust
@@ -272,7 +427,15 @@ assert_eq!(f.count_zeros(), 3);
             comment! {
                 "Returns the number of leading zeros in the binary
 representation.
-
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "
+The most significant bit of a negative number is set, so a negative
+number has no leading zeros.
+",
+                },
+                "
 # Examples
 
 ```rust
@@ -309,6 +472,9 @@ assert_eq!(f.trailing_zeros(), 5);
                 "Shifts to the left by `n` bits, wrapping the
 truncated bits to the right end.
 
+This operates on the bit pattern, not on the value; the result is the
+same as rotating the underlying integer returned by [`to_bits`].
+
 # Examples
 
 ```rust
@@ -319,6 +485,8 @@ let rot = 0b1010111;
 assert_eq!(bits.rotate_left(3), rot);
 assert_eq!(Fix::from_bits(bits).rotate_left(3), Fix::from_bits(rot));
 ```
+
+[`to_bits`]: #method.to_bits
 ";
                 #[inline]
                 pub const fn rotate_left(self, n: u32) -> $Fixed<Frac> {
@@ -329,6 +497,9 @@ assert_eq!(Fix::from_bits(bits).rotate_left(3), Fix::from_bits(rot));
                 "Shifts to the right by `n` bits, wrapping the
 truncated bits to the left end.
 
+This operates on the bit pattern, not on the value; the result is the
+same as rotating the underlying integer returned by [`to_bits`].
+
 # Examples
 
 ```rust
@@ -339,12 +510,62 @@ let rot = (0b111 << (", $s_nbits, " - 3)) | 0b1010;
 assert_eq!(bits.rotate_right(3), rot);
 assert_eq!(Fix::from_bits(bits).rotate_right(3), Fix::from_bits(rot));
 ```
+
+[`to_bits`]: #method.to_bits
 ";
                 #[inline]
                 pub const fn rotate_right(self, n: u32) -> $Fixed<Frac> {
                     Self::from_bits(self.to_bits().rotate_right(n))
                 }
             }
+            comment! {
+                "Reverses the byte order of the fixed-point number.
+
+This operates on the bit pattern, not on the value; the result is the
+same as swapping the bytes of the underlying integer returned by
+[`to_bits`].
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let f = Fix::from_bits(0x12);
+assert_eq!(f.swap_bytes(), Fix::from_bits((0x12 as ", $s_inner, ").swap_bytes()));
+assert_eq!(f.swap_bytes(), Fix::from_le_bytes(f.to_be_bytes()));
+```
+
+[`to_bits`]: #method.to_bits
+";
+                #[inline]
+                pub const fn swap_bytes(self) -> $Fixed<Frac> {
+                    Self::from_bits(self.to_bits().swap_bytes())
+                }
+            }
+            comment! {
+                "Reverses the order of the bits of the fixed-point number.
+
+This operates on the bit pattern, not on the value; the result is the
+same as reversing the bits of the underlying integer returned by
+[`to_bits`].
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let bits = 0b1011 as ", $s_inner, ";
+let rev = (0b1101 as ", $s_inner, ") << (", $s_nbits, " - 4);
+assert_eq!(Fix::from_bits(bits).reverse_bits(), Fix::from_bits(rev));
+```
+
+[`to_bits`]: #method.to_bits
+";
+                #[inline]
+                pub const fn reverse_bits(self) -> $Fixed<Frac> {
+                    Self::from_bits(self.to_bits().reverse_bits())
+                }
+            }
 
             if_signed! {
                 $Signedness;
@@ -474,6 +695,10 @@ assert_eq!(minus_five.abs(), five);
                 comment! {
                     "Returns the smallest power of two that is ≥ `self`.
 
+The power of two can have a negative exponent, so that for example the
+next power of two of 3/8 is 1/2. The next power of two of zero is the
+smallest positive value, that is [`DELTA`].
+
 # Panics
 
 When debug assertions are enabled, panics if the next power of two is
@@ -493,13 +718,369 @@ let three_eights = Fix::from_bits(0b0110);
 let half = Fix::from_bits(0b1000);
 assert_eq!(three_eights.next_power_of_two(), half);
 assert_eq!(half.next_power_of_two(), half);
+assert_eq!(Fix::from_num(0).next_power_of_two(), Fix::DELTA);
+```
+
+[`DELTA`]: #associatedconstant.DELTA
+[`checked_next_power_of_two`]: #method.checked_next_power_of_two
+";
+                    #[inline]
+                    pub fn next_power_of_two(self) -> $Fixed<Frac> {
+                        Self::from_bits(self.to_bits().next_power_of_two())
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the absolute value of the difference between
+`self` and `other`.
+
+",
+                if_signed_unsigned! {
+                    $Signedness,
+                    concat!(
+                        "The result is returned as the unsigned [`",
+                        stringify!($UFixed),
+                        "`] with the same number of fractional bits, so it
+cannot overflow even when `self` and `other` are at opposite ends of
+the range.
+
+[`",
+                        stringify!($UFixed),
+                        "`]: struct.",
+                        stringify!($UFixed),
+                        ".html",
+                    ),
+                    "This cannot overflow.",
+                },
+                "
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed,
+                if_signed_else_empty_str! { $Signedness, ", ", stringify!($UFixed) },
+                "};
+type Fix = ", $s_fixed, "<U4>;
+type UFix = ", stringify!($UFixed), "<U4>;
+assert_eq!(Fix::from_num(2).abs_diff(Fix::from_num(5)), UFix::from_num(3));
+assert_eq!(Fix::from_num(5).abs_diff(Fix::from_num(2)), UFix::from_num(3));
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-2).abs_diff(Fix::from_num(5)), UFix::from_num(7));
+assert_eq!(Fix::max_value().abs_diff(Fix::min_value()), UFix::max_value());
+",
+                },
+                "```
+";
+                #[inline]
+                pub const fn abs_diff(self, other: $Fixed<Frac>) -> $UFixed<Frac> {
+                    let (lo, hi) = if self.to_bits() < other.to_bits() {
+                        (self.to_bits(), other.to_bits())
+                    } else {
+                        (other.to_bits(), self.to_bits())
+                    };
+                    $UFixed::from_bits((hi as $UInner).wrapping_sub(lo as $UInner))
+                }
+            }
+
+            comment! {
+                "Returns the absolute value using an unsigned type
+without any wrapping or panicking.
+
+",
+                if_signed_unsigned! {
+                    $Signedness,
+                    concat!(
+                        "The result is of type [`",
+                        stringify!($UFixed),
+                        "`], so even the absolute value of [`min_value`]
+can be represented.
+
+[`min_value`]: #method.min_value
+[`",
+                        stringify!($UFixed),
+                        "`]: struct.",
+                        stringify!($UFixed),
+                        ".html",
+                    ),
+                    "For unsigned numbers this returns `self`.",
+                },
+                "
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed,
+                if_signed_else_empty_str! { $Signedness, ", ", stringify!($UFixed) },
+                "};
+type Fix = ", $s_fixed, "<U4>;
+type UFix = ", stringify!($UFixed), "<U4>;
+assert_eq!(Fix::from_num(5).unsigned_abs(), UFix::from_num(5));
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-5).unsigned_abs(), UFix::from_num(5));
+assert_eq!(Fix::min_value().unsigned_abs(), UFix::from_bits(1 << (", $s_nbits, " - 1)));
+",
+                },
+                "```
+";
+                #[inline]
+                pub const fn unsigned_abs(self) -> $UFixed<Frac> {
+                    $UFixed::from_bits(
+                        if_signed_unsigned! {
+                            $Signedness,
+                            self.to_bits().wrapping_abs() as $UInner,
+                            self.to_bits(),
+                        }
+                    )
+                }
+            }
+
+            comment! {
+                "Returns the smaller of `self` and `other`.
+
+This is the same as [`Ord::min`], but is available as an inherent method.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2).min(Fix::from_num(5)), Fix::from_num(2));
+assert_eq!(Fix::from_num(5).min(Fix::from_num(2)), Fix::from_num(2));
+```
+
+[`Ord::min`]: https://doc.rust-lang.org/nightly/core/cmp/trait.Ord.html#method.min
+";
+                #[inline]
+                pub const fn min(self, other: $Fixed<Frac>) -> $Fixed<Frac> {
+                    if other.to_bits() < self.to_bits() {
+                        other
+                    } else {
+                        self
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the larger of `self` and `other`.
+
+This is the same as [`Ord::max`], but is available as an inherent method.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2).max(Fix::from_num(5)), Fix::from_num(5));
+assert_eq!(Fix::from_num(5).max(Fix::from_num(2)), Fix::from_num(5));
+```
+
+[`Ord::max`]: https://doc.rust-lang.org/nightly/core/cmp/trait.Ord.html#method.max
+";
+                #[inline]
+                pub const fn max(self, other: $Fixed<Frac>) -> $Fixed<Frac> {
+                    if other.to_bits() < self.to_bits() {
+                        self
+                    } else {
+                        other
+                    }
+                }
+            }
+
+            comment! {
+                "Restricts the value to the range [`min`, `max`].
+
+Returns `max` if `self` is greater than `max`, and `min` if `self` is
+less than `min`. Otherwise returns `self`.
+
+# Panics
+
+Panics in debug mode if `min` > `max`.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let (min, max) = (Fix::from_num(2), Fix::from_num(5));
+assert_eq!(Fix::from_num(1).clamp(min, max), min);
+assert_eq!(Fix::from_num(3.5).clamp(min, max), Fix::from_num(3.5));
+assert_eq!(Fix::from_num(7).clamp(min, max), max);
+```
+";
+                #[inline]
+                pub fn clamp(self, min: $Fixed<Frac>, max: $Fixed<Frac>) -> $Fixed<Frac> {
+                    debug_assert!(min.to_bits() <= max.to_bits(), "min > max");
+                    if self.to_bits() < min.to_bits() {
+                        min
+                    } else if self.to_bits() > max.to_bits() {
+                        max
+                    } else {
+                        self
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the midpoint of `self` and `other`, rounding
+towards zero.
+
+This is the same as `(self + other) / 2` computed with enough bits so
+that it cannot overflow, and the rounding is towards zero like
+integer division.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2).midpoint(Fix::from_num(3)), Fix::from_num(2.5));
+assert_eq!(Fix::max_value().midpoint(Fix::max_value()), Fix::max_value());
+// 0.0001 + 0.0010 = 0.0011 in binary; half of it is rounded down
+assert_eq!(Fix::from_bits(1).midpoint(Fix::from_bits(2)), Fix::from_bits(1));
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "// −0.0001 − 0.0010 = −0.0011 in binary; half of it is rounded up
+assert_eq!(Fix::from_bits(-1).midpoint(Fix::from_bits(-2)), Fix::from_bits(-1));
+assert_eq!(Fix::min_value().midpoint(Fix::max_value()), Fix::from_num(0));
+",
+                },
+                "```
+";
+                #[inline]
+                pub const fn midpoint(self, other: $Fixed<Frac>) -> $Fixed<Frac> {
+                    let (a, b) = (self.to_bits(), other.to_bits());
+                    // rounded towards −∞
+                    let floor = (a & b) + ((a ^ b) >> 1);
+                    if_signed_unsigned! {
+                        $Signedness,
+                        if floor < 0 && (a ^ b) & 1 != 0 {
+                            Self::from_bits(floor + 1)
+                        } else {
+                            Self::from_bits(floor)
+                        },
+                        Self::from_bits(floor),
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the smallest multiple of `rhs` that is ≥ `self`",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    " if `rhs` is positive, or the largest multiple of `rhs`
+that is ≤ `self` if `rhs` is negative",
+                },
+                ".
+
+The multiple is computed exactly on the underlying bits, so `rhs` can
+have a fractional part.
+
+# Panics
+
+Panics if `rhs` is zero.
+
+When debug assertions are enabled, also panics if the result
+overflows. When debug assertions are not enabled, the wrapped value
+can be returned, but it is not considered a breaking change if in the
+future it panics; use [`checked_next_multiple_of`] to detect overflow.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(7).next_multiple_of(Fix::from_num(2.5)), Fix::from_num(7.5));
+assert_eq!(Fix::from_num(7.5).next_multiple_of(Fix::from_num(2.5)), Fix::from_num(7.5));
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-7).next_multiple_of(Fix::from_num(2.5)), Fix::from_num(-5));
+assert_eq!(Fix::from_num(7).next_multiple_of(Fix::from_num(-2.5)), Fix::from_num(5));
+",
+                },
+                "```
+
+[`checked_next_multiple_of`]: #method.checked_next_multiple_of
+";
+                #[inline]
+                pub fn next_multiple_of(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                    let (lhs, rhs) = (self.to_bits(), rhs.to_bits());
+                    let rem = if_signed_unsigned! {
+                        $Signedness,
+                        {
+                            if rhs == -1 {
+                                return self;
+                            }
+                            let rem = lhs % rhs;
+                            if (rem > 0 && rhs < 0) || (rem < 0 && rhs > 0) {
+                                rem + rhs
+                            } else {
+                                rem
+                            }
+                        },
+                        lhs % rhs,
+                    };
+                    if rem == 0 {
+                        self
+                    } else {
+                        Self::from_bits(lhs + (rhs - rem))
+                    }
+                }
+            }
+
+            comment! {
+                "Checked next multiple of `rhs`. Returns the smallest
+multiple of `rhs` that is ≥ `self`",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    " if `rhs` is positive, or the largest multiple of `rhs`
+that is ≤ `self` if `rhs` is negative",
+                },
+                ", returning [`None`] if `rhs` is zero or on overflow.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(
+    Fix::from_num(7).checked_next_multiple_of(Fix::from_num(2.5)),
+    Some(Fix::from_num(7.5))
+);
+assert_eq!(Fix::from_num(7).checked_next_multiple_of(Fix::from_num(0)), None);
+assert_eq!(Fix::max_value().checked_next_multiple_of(Fix::from_num(2)), None);
 ```
 
-[`checked_next_power_of_two`]: #method.checked_next_power_of_two
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
 ";
-                    #[inline]
-                    pub fn next_power_of_two(self) -> $Fixed<Frac> {
-                        Self::from_bits(self.to_bits().next_power_of_two())
+                #[inline]
+                pub fn checked_next_multiple_of(self, rhs: $Fixed<Frac>) -> Option<$Fixed<Frac>> {
+                    let (lhs, rhs) = (self.to_bits(), rhs.to_bits());
+                    let rem = if_signed_unsigned! {
+                        $Signedness,
+                        {
+                            if rhs == -1 {
+                                return Some(self);
+                            }
+                            let rem = lhs.checked_rem(rhs)?;
+                            if (rem > 0 && rhs < 0) || (rem < 0 && rhs > 0) {
+                                rem + rhs
+                            } else {
+                                rem
+                            }
+                        },
+                        lhs.checked_rem(rhs)?,
+                    };
+                    if rem == 0 {
+                        Some(self)
+                    } else {
+                        lhs.checked_add(rhs - rem).map(Self::from_bits)
                     }
                 }
             }
@@ -581,6 +1162,35 @@ assert_eq!(Fix::min_value().checked_sub(one), None);
                 }
             }
 
+            comment! {
+                "Checked sum. Returns the sum of the values starting from zero,
+or [`None`] on the first overflow.
+
+Unlike [`Sum`], this never panics.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let values = [Fix::from_num(1), Fix::from_num(2.5)];
+assert_eq!(Fix::checked_sum(values.iter().cloned()), Some(Fix::from_num(3.5)));
+let values = [Fix::max_value(), Fix::from_num(1)];
+assert_eq!(Fix::checked_sum(values.iter().cloned()), None);
+```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+[`Sum`]: https://doc.rust-lang.org/nightly/core/iter/trait.Sum.html
+";
+                #[inline]
+                pub fn checked_sum<I>(mut iter: I) -> Option<$Fixed<Frac>>
+                where
+                    I: Iterator<Item = $Fixed<Frac>>,
+                {
+                    iter.try_fold(Self::from_bits(0), Self::checked_add)
+                }
+            }
+
             comment! {
                 "Checked remainder. Returns the remainder, or [`None`] if
 the divisor is zero.
@@ -615,6 +1225,148 @@ assert_eq!(Fix::from_num(1.5).checked_rem(Fix::from_num(0)), None);
                 }
             }
 
+            comment! {
+                "Multiplication by an integer.
+
+The backing integer is multiplied by `rhs` directly, so the product is
+exact and cheaper than multiplication by a fixed-point number. This is
+the same as the `*` operator with an integer operand.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result
+overflows. When debug assertions are not enabled, the wrapped value can
+be returned, but it is not considered a breaking change if in the
+future it panics; if wrapping is required use [`wrapping_mul_int`]
+instead.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(0.5).mul_int(3), Fix::from_num(1.5));
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(0.5).mul_int(-3), Fix::from_num(-1.5));
+",
+                },
+                "```
+
+[`wrapping_mul_int`]: #method.wrapping_mul_int
+";
+                #[inline]
+                pub const fn mul_int(self, rhs: $Inner) -> $Fixed<Frac> {
+                    Self::from_bits(self.to_bits() * rhs)
+                }
+            }
+
+            comment! {
+                "Division by an integer, truncating towards zero.
+
+The backing integer is divided by `rhs` directly. This is the same as
+the `/` operator with an integer operand. To round to the nearest
+instead, use [`div_int_round`].
+
+# Panics
+
+Panics if the divisor is zero",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    " or if the division results in overflow",
+                },
+                ".
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(7).div_int(2), Fix::from_num(3.5));
+// 1/16 ÷ 2 = 1/32 is truncated to zero
+assert_eq!(Fix::from_bits(1).div_int(2), Fix::from_num(0));
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-7).div_int(2), Fix::from_num(-3.5));
+assert_eq!(Fix::from_bits(-1).div_int(2), Fix::from_num(0));
+",
+                },
+                "```
+
+[`div_int_round`]: #method.div_int_round
+";
+                #[inline]
+                pub fn div_int(self, rhs: $Inner) -> $Fixed<Frac> {
+                    Self::from_bits(self.to_bits() / rhs)
+                }
+            }
+
+            comment! {
+                "Division by an integer, rounding to the nearest.
+
+Ties are rounded away from zero, like [`round`].
+
+# Panics
+
+Panics if the divisor is zero",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    " or if the division results in overflow",
+                },
+                ".
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(7).div_int_round(2), Fix::from_num(3.5));
+// 5/16 ÷ 2 = 2.5/16 is a tie, rounded away from zero to 3/16
+assert_eq!(Fix::from_bits(5).div_int_round(2), Fix::from_bits(3));
+// 5/16 ÷ 3 = 1.67/16 is rounded to 2/16
+assert_eq!(Fix::from_bits(5).div_int_round(3), Fix::from_bits(2));
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_bits(-5).div_int_round(2), Fix::from_bits(-3));
+assert_eq!(Fix::from_bits(5).div_int_round(-3), Fix::from_bits(-2));
+",
+                },
+                "```
+
+[`round`]: #method.round
+";
+                #[inline]
+                pub fn div_int_round(self, rhs: $Inner) -> $Fixed<Frac> {
+                    let bits = self.to_bits();
+                    let (quot, rem) = (bits / rhs, bits % rhs);
+                    if_signed_unsigned! {
+                        $Signedness,
+                        {
+                            // |rem| < |rhs|, so |rem| ≥ |rhs| − |rem| cannot overflow
+                            let (abs_rem, abs_rhs) = (rem.unsigned_abs(), rhs.unsigned_abs());
+                            if abs_rem >= abs_rhs - abs_rem {
+                                // |rhs| ≥ 2 here, so quot ± 1 cannot overflow
+                                if (bits < 0) == (rhs < 0) {
+                                    Self::from_bits(quot + 1)
+                                } else {
+                                    Self::from_bits(quot - 1)
+                                }
+                            } else {
+                                Self::from_bits(quot)
+                            }
+                        },
+                        if rem >= rhs - rem {
+                            Self::from_bits(quot + 1)
+                        } else {
+                            Self::from_bits(quot)
+                        },
+                    }
+                }
+            }
+
             comment! {
                 "Checked multiplication by an integer. Returns the
 product, or [`None`] on overflow.
@@ -735,6 +1487,9 @@ assert_eq!((Fix::from_num(1) / 2).checked_shl(", $s_nbits, "), None);
                 "Checked shift right. Returns the shifted number,
 or [`None`] if `rhs` ≥ ", $s_nbits, ".
 
+Bits shifted out are discarded, so the result is rounded towards
+negative infinity, the same as with the `>>` operator.
+
 # Examples
 
 ```rust
@@ -742,7 +1497,16 @@ use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
 type Fix = ", $s_fixed, "<U4>;
 assert_eq!(Fix::from_num(4).checked_shr(3), Some(Fix::from_num(1) / 2));
 assert_eq!(Fix::from_num(4).checked_shr(", $s_nbits, "), None);
-```
+// 0.1875 >> 2 = 0.046875, rounded down to 0
+assert_eq!(Fix::from_num(0.1875).checked_shr(2), Some(Fix::from_num(0)));
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "// -0.1875 >> 2 = -0.046875, rounded down to -0.0625
+assert_eq!(Fix::from_num(-0.1875).checked_shr(2), Some(Fix::from_num(-0.0625)));
+",
+                },
+                "```
 
 [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
 ";
@@ -936,6 +1700,46 @@ assert_eq!(Fix::max_value().saturating_mul_int(2), Fix::max_value());
                 }
             }
 
+            comment! {
+                "Saturating division by an integer. Returns the quotient",
+                if_signed_unsigned! {
+                    $Signedness,
+                    ", saturating on overflow.
+
+Overflow can only occur when dividing the minimum value by −1.",
+                    ".
+
+Can never overflow for unsigned values.",
+                },
+                "
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 1.5 is binary 1.1
+let one_point_5 = Fix::from_bits(0b11 << (4 - 1));
+assert_eq!(Fix::from_num(3).saturating_div_int(2), one_point_5);
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::min_value().saturating_div_int(-1), Fix::max_value());
+",
+                },
+                "```
+";
+                #[inline]
+                pub fn saturating_div_int(self, rhs: $Inner) -> $Fixed<Frac> {
+                    let (val, overflow) = self.overflowing_div_int(rhs);
+                    val.if_cond_else(!overflow, Self::max_value())
+                }
+            }
+
             if_signed! {
                 $Signedness;
                 comment! {
@@ -960,6 +1764,36 @@ assert_eq!(Fix::min_value().saturating_abs(), Fix::max_value());
                 }
             }
 
+            if_unsigned! {
+                $Signedness;
+                comment! {
+                    "Saturating next power of two. Returns the smallest power
+of two that is ≥ `self`, saturating to the maximum value if the next
+power of two is too large to represent.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 3/8 is 0.0110
+let three_eights = Fix::from_bits(0b0110);
+// 1/2 is 0.1000
+let half = Fix::from_bits(0b1000);
+assert_eq!(three_eights.saturating_next_power_of_two(), half);
+assert_eq!(Fix::max_value().saturating_next_power_of_two(), Fix::max_value());
+```
+";
+                    #[inline]
+                    pub fn saturating_next_power_of_two(self) -> $Fixed<Frac> {
+                        match self.to_bits().checked_next_power_of_two() {
+                            Some(bits) => Self::from_bits(bits),
+                            None => Self::max_value(),
+                        }
+                    }
+                }
+            }
+
             comment! {
                 "Wrapping negation. Returns the negated value, wrapping on overflow.
 
@@ -1163,6 +1997,33 @@ assert_eq!(Fix::min_value().wrapping_abs(), Fix::min_value());
                 }
             }
 
+            if_unsigned! {
+                $Signedness;
+                comment! {
+                    "Wrapping next power of two. Returns the smallest power
+of two that is ≥ `self`, or zero if the next power of two is too large
+to represent.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 3/8 is 0.0110
+let three_eights = Fix::from_bits(0b0110);
+// 1/2 is 0.1000
+let half = Fix::from_bits(0b1000);
+assert_eq!(three_eights.wrapping_next_power_of_two(), half);
+assert_eq!(Fix::max_value().wrapping_next_power_of_two(), 0);
+```
+";
+                    #[inline]
+                    pub fn wrapping_next_power_of_two(self) -> $Fixed<Frac> {
+                        self.checked_next_power_of_two().unwrap_or(Self::from_bits(0))
+                    }
+                }
+            }
+
             comment! {
                 "Overflowing negation.
 
@@ -1268,6 +2129,54 @@ assert_eq!(Fix::from_num(0)",
                 }
             }
 
+            comment! {
+                "Returns [`true`][`bool`] if the addition would overflow.
+
+This is equivalent to `self.overflowing_add(rhs).1`, without
+materializing the sum.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let one = Fix::from_num(1);
+assert!(!(Fix::max_value() - one).add_overflows(one));
+assert!(Fix::max_value().add_overflows(Fix::from_bits(1)));
+```
+
+[`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+";
+                #[inline]
+                pub const fn add_overflows(self, rhs: $Fixed<Frac>) -> bool {
+                    self.to_bits().overflowing_add(rhs.to_bits()).1
+                }
+            }
+
+            comment! {
+                "Returns [`true`][`bool`] if the subtraction would overflow.
+
+This is equivalent to `self.overflowing_sub(rhs).1`, without
+materializing the difference.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let one = Fix::from_num(1);
+assert!(!(Fix::min_value() + one).sub_overflows(one));
+assert!(Fix::min_value().sub_overflows(Fix::from_bits(1)));
+```
+
+[`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+";
+                #[inline]
+                pub const fn sub_overflows(self, rhs: $Fixed<Frac>) -> bool {
+                    self.to_bits().overflowing_sub(rhs.to_bits()).1
+                }
+            }
+
             comment! {
                 "Overflowing multiplication by an integer.
 
@@ -1420,6 +2329,44 @@ assert_eq!(Fix::min_value().overflowing_abs(), (Fix::min_value(), true));
                 }
             }
 
+            if_unsigned! {
+                $Signedness;
+                comment! {
+                    "Overflowing next power of two.
+
+Returns a [tuple] of the smallest power of two that is ≥ `self` and a
+[`bool`] indicating whether an overflow has occurred. On overflow, zero
+is returned.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 3/8 is 0.0110
+let three_eights = Fix::from_bits(0b0110);
+// 1/2 is 0.1000
+let half = Fix::from_bits(0b1000);
+assert_eq!(three_eights.overflowing_next_power_of_two(), (half, false));
+assert_eq!(
+    Fix::max_value().overflowing_next_power_of_two(),
+    (Fix::from_num(0), true)
+);
+```
+
+[`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+[tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+";
+                    #[inline]
+                    pub fn overflowing_next_power_of_two(self) -> ($Fixed<Frac>, bool) {
+                        match self.checked_next_power_of_two() {
+                            Some(ans) => (ans, false),
+                            None => (Self::from_bits(0), true),
+                        }
+                    }
+                }
+            }
+
             #[inline]
             const fn if_cond_else(self, cond: bool, otherwise: Self) -> Self {
                 let not_mask = (cond as $Inner).wrapping_sub(1);
@@ -105,6 +105,89 @@ assert_eq!((-two_and_quarter).frac(), three_quarters);
             }
         }
 
+        comment! {
+            "Returns the integer part, rounded towards zero.
+
+The fractional part is returned by [`frac_part`], and
+`self.int_part() + self.frac_part()` is always equal to `self`.
+
+",
+            if_signed_unsigned! {
+                $Signedness,
+                "Unlike [`int`], which rounds negative numbers towards −∞, this
+is the same as [`round_to_zero`].",
+                "Note that for unsigned numbers, this is equivalent to [`int`].",
+            },
+            "
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2.25).int_part(), Fix::from_num(2));
+",
+            if_signed_else_empty_str! {
+                $Signedness,
+                "assert_eq!(Fix::from_num(-2.25).int_part(), Fix::from_num(-2));
+assert_eq!(Fix::from_num(-2.25).int(), Fix::from_num(-3));
+",
+            },
+            "```
+
+[`frac_part`]: #method.frac_part
+[`int`]: #method.int
+",
+            if_signed_else_empty_str! {
+                $Signedness,
+                "[`round_to_zero`]: #method.round_to_zero
+",
+            };
+            #[inline]
+            pub fn int_part(self) -> $Fixed<Frac> {
+                self.round_to_zero()
+            }
+        }
+
+        comment! {
+            "Returns the fractional part, with the same sign as `self`.
+
+The integer part is returned by [`int_part`], and
+`self.int_part() + self.frac_part()` is always equal to `self`.
+
+",
+            if_signed_unsigned! {
+                $Signedness,
+                "Unlike [`frac`], which is never negative, the fractional
+part of a negative number is negative or zero.",
+                "Note that for unsigned numbers, this is equivalent to [`frac`].",
+            },
+            "
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2.25).frac_part(), Fix::from_num(0.25));
+",
+            if_signed_else_empty_str! {
+                $Signedness,
+                "assert_eq!(Fix::from_num(-2.25).frac_part(), Fix::from_num(-0.25));
+assert_eq!(Fix::from_num(-2.25).frac(), Fix::from_num(0.75));
+",
+            },
+            "```
+
+[`frac`]: #method.frac
+[`int_part`]: #method.int_part
+";
+            #[inline]
+            pub fn frac_part(self) -> $Fixed<Frac> {
+                self - self.round_to_zero()
+            }
+        }
+
         comment! {
             "Rounds to the next integer towards 0.
 
@@ -138,6 +221,7 @@ assert_eq!(Fix::from_num(-2.9).round_to_zero(), Fix::from_num(-2));
 "
             };
             #[inline]
+            #[doc(alias = "trunc")]
             pub fn round_to_zero(self) -> $Fixed<Frac> {
                 if_signed! {
                     $Signedness;
@@ -291,6 +375,7 @@ assert_eq!(Fix::from_num(3.5).round_ties_to_even(), Fix::from_num(4));
 [`wrapping_round_ties_to_even`]: #method.wrapping_round_ties_to_even
 ";
             #[inline]
+            #[doc(alias = "round_ties_even")]
             pub fn round_ties_to_even(self) -> $Fixed<Frac> {
                 let (round, overflow) = self.overflowing_round_ties_to_even();
                 debug_assert!(!overflow, "overflow");
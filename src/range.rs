@@ -0,0 +1,173 @@
+// Copyright © 2018–2019 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::{helpers::Widest, traits::Fixed};
+use core::{convert::TryFrom, iter::FusedIterator};
+
+/// Returns an iterator over the fixed-point numbers `start`,
+/// `start + step`, `start + 2 * step`, … that are less than `end`.
+///
+/// Fixed-point addition is exact, so unlike a floating-point loop no
+/// error accumulates: the <i>i</i>th value is exactly
+/// `start + i * step`. If the next value would overflow, the
+/// iteration ends, as that value would not be less than `end` anyway.
+///
+/// # Panics
+///
+/// Panics if `step` is not positive.
+///
+/// # Examples
+///
+/// ```rust
+/// use substrate_fixed::{range, types::U0F16};
+/// let quarter = U0F16::from_num(0.25);
+/// let values: Vec<U0F16> = range(U0F16::from_num(0), U0F16::max_value(), quarter).collect();
+/// assert_eq!(values, [0.0, 0.25, 0.5, 0.75]);
+/// ```
+#[inline]
+pub fn range<F: Fixed>(start: F, end: F, step: F) -> FixedRange<F> {
+    assert!(
+        step > F::from_bits(F::Bits::default()),
+        "step must be positive"
+    );
+    FixedRange {
+        next: Some(start),
+        end,
+        step,
+    }
+}
+
+/// An iterator over evenly spaced fixed-point numbers.
+///
+/// This `struct` is created by the [`range`] function. See its
+/// documentation for more.
+///
+/// [`range`]: fn.range.html
+#[derive(Clone, Copy, Debug)]
+pub struct FixedRange<F> {
+    next: Option<F>,
+    end: F,
+    step: F,
+}
+
+// Returns the bits of a fixed-point number as u128 in two's
+// complement, so that the wrapping difference of two numbers is exact.
+#[inline]
+fn raw_bits<F: Fixed>(val: F) -> u128 {
+    match val
+        .private_to_fixed_helper(F::FRAC_NBITS, F::INT_NBITS)
+        .bits
+    {
+        Widest::Unsigned(bits) => bits,
+        Widest::Negative(bits) => bits as u128,
+    }
+}
+
+impl<F: Fixed> Iterator for FixedRange<F> {
+    type Item = F;
+
+    #[inline]
+    fn next(&mut self) -> Option<F> {
+        let cur = self.next?;
+        if cur >= self.end {
+            self.next = None;
+            return None;
+        }
+        self.next = cur.checked_add(self.step);
+        Some(cur)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let cur = match self.next {
+            Some(cur) if cur < self.end => cur,
+            _ => return (0, Some(0)),
+        };
+        let diff = raw_bits(self.end).wrapping_sub(raw_bits(cur));
+        let count = (diff - 1) / raw_bits(self.step) + 1;
+        match usize::try_from(count) {
+            Ok(count) => (count, Some(count)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+impl<F: Fixed> FusedIterator for FixedRange<F> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        range,
+        types::{I16F16, I8F8, U0F16, U0F8, U128F0},
+    };
+    use std::vec::Vec;
+
+    #[test]
+    fn quarters() {
+        let quarter = U0F16::from_num(0.25);
+        let values: Vec<U0F16> = range(U0F16::from_num(0), U0F16::max_value(), quarter).collect();
+        assert_eq!(values, [0.0, 0.25, 0.5, 0.75]);
+        let iter = range(U0F16::from_num(0), U0F16::max_value(), quarter);
+        assert_eq!(iter.size_hint(), (4, Some(4)));
+
+        let values: Vec<I16F16> = range(
+            I16F16::from_num(0),
+            I16F16::from_num(1),
+            I16F16::from_num(0.25),
+        )
+        .collect();
+        assert_eq!(values, [0.0, 0.25, 0.5, 0.75]);
+    }
+
+    #[test]
+    fn no_accumulated_error() {
+        let step = I16F16::from_num(0.1);
+        let start = I16F16::from_num(-3);
+        for (i, val) in range(start, I16F16::from_num(3), step).enumerate() {
+            assert_eq!(val, start + step * I16F16::from_num(i));
+        }
+    }
+
+    #[test]
+    fn size_hint_and_overflow() {
+        let mut iter = range(I8F8::from_num(-100), I8F8::max_value(), I8F8::from_num(50));
+        assert_eq!(iter.size_hint(), (5, Some(5)));
+        assert_eq!(iter.by_ref().count(), 5);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+
+        // the next value overflows, which ends the iteration
+        let delta = U0F8::from_bits(1);
+        let iter = range(U0F8::max_value() - delta, U0F8::max_value(), delta);
+        assert_eq!(iter.count(), 1);
+
+        let empty = range(I8F8::from_num(1), I8F8::from_num(1), I8F8::from_num(1));
+        assert_eq!(empty.size_hint(), (0, Some(0)));
+        assert_eq!(empty.count(), 0);
+
+        let huge = range(
+            U128F0::from_num(0),
+            U128F0::max_value(),
+            U128F0::from_num(1),
+        );
+        assert_eq!(huge.size_hint(), (usize::MAX, None));
+    }
+
+    #[test]
+    #[should_panic(expected = "step must be positive")]
+    fn zero_step() {
+        let _ = range(I8F8::from_num(0), I8F8::from_num(1), I8F8::from_num(0));
+    }
+}
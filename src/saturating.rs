@@ -0,0 +1,340 @@
+// Copyright © 2018–2019 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::{
+    from_str::ParseFixedError,
+    traits::{Fixed, FromFixed, ToFixed},
+};
+use core::{
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+/// Provides saturating arithmetic on fixed-point numbers.
+///
+/// Results that do not fit are clamped to the minimum or maximum
+/// value instead of panicking or wrapping.
+///
+/// The underlying value can be retrieved through the `.0` index.
+///
+/// # Examples
+///
+/// ```rust
+/// use substrate_fixed::{types::I16F16, Saturating};
+/// let max = Saturating(I16F16::max_value());
+/// let delta = Saturating(I16F16::from_bits(1));
+/// assert_eq!(I16F16::max_value(), (max + delta).0);
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Saturating<F>(pub F);
+
+impl<F: Fixed> Saturating<F> {
+    /// Returns the smallest value that can be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Saturating};
+    /// assert_eq!(Saturating::<I16F16>::min_value(), Saturating(I16F16::min_value()));
+    /// ```
+    #[inline]
+    pub fn min_value() -> Saturating<F> {
+        Saturating(F::min_value())
+    }
+
+    /// Returns the largest value that can be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Saturating};
+    /// assert_eq!(Saturating::<I16F16>::max_value(), Saturating(I16F16::max_value()));
+    /// ```
+    #[inline]
+    pub fn max_value() -> Saturating<F> {
+        Saturating(F::max_value())
+    }
+
+    /// Saturating conversion from another number.
+    ///
+    /// The other number can be any number for which [`ToFixed`] is
+    /// implemented, in which case this method returns
+    /// <code>[Saturating][`Saturating`]([src.saturating_to_fixed()][`saturating_to_fixed`])</code>.
+    ///
+    /// # Panics
+    ///
+    /// For floating-point numbers, panics if the value is [NaN].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I4F4, Saturating};
+    /// assert_eq!(Saturating::<I4F4>::from_num(129.75), Saturating(I4F4::max_value()));
+    /// assert_eq!(Saturating::<I4F4>::from_num(-1000), Saturating(I4F4::min_value()));
+    /// ```
+    ///
+    /// [NaN]: https://doc.rust-lang.org/nightly/std/primitive.f64.html#method.is_nan
+    /// [`Saturating`]: struct.Saturating.html
+    /// [`ToFixed`]: traits/trait.ToFixed.html
+    /// [`saturating_to_fixed`]: traits/trait.ToFixed.html#tymethod.saturating_to_fixed
+    #[inline]
+    pub fn from_num<Src: ToFixed>(src: Src) -> Saturating<F> {
+        Saturating(src.saturating_to_fixed())
+    }
+
+    /// Converts a fixed-point number to another number, saturating the
+    /// value on overflow.
+    ///
+    /// The other number can be any number for which [`FromFixed`] is
+    /// implemented, in which case this method returns
+    /// [`Dst::saturating_from_fixed(self.0)`][`saturating_from_fixed`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Saturating};
+    /// let s = Saturating(I16F16::from_num(-300.25));
+    /// assert_eq!(s.to_num::<u8>(), 0);
+    /// assert_eq!(s.to_num::<i8>(), -128);
+    /// ```
+    ///
+    /// [`FromFixed`]: traits/trait.FromFixed.html
+    /// [`saturating_from_fixed`]: traits/trait.FromFixed.html#tymethod.saturating_from_fixed
+    #[inline]
+    pub fn to_num<Dst: FromFixed>(self) -> Dst {
+        Dst::saturating_from_fixed(self.0)
+    }
+}
+
+impl<F: Fixed> Display for Saturating<F> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<F: Fixed> Debug for Saturating<F> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl<F: Fixed> From<F> for Saturating<F> {
+    /// Wraps a fixed-point number.
+    #[inline]
+    fn from(src: F) -> Saturating<F> {
+        Saturating(src)
+    }
+}
+
+impl<F: Fixed> FromStr for Saturating<F> {
+    type Err = ParseFixedError;
+    /// Parses a string slice containing decimal digits to return a fixed-point number.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        F::saturating_from_str(s).map(Saturating)
+    }
+}
+
+macro_rules! op {
+    ($saturating:ident, $Op:ident $op:ident, $OpAssign:ident $op_assign:ident) => {
+        impl<F: Fixed> $Op<Saturating<F>> for Saturating<F> {
+            type Output = Saturating<F>;
+            #[inline]
+            fn $op(self, other: Saturating<F>) -> Saturating<F> {
+                Saturating((self.0).$saturating(other.0))
+            }
+        }
+        impl<'a, F: Fixed> $Op<Saturating<F>> for &'a Saturating<F> {
+            type Output = Saturating<F>;
+            #[inline]
+            fn $op(self, other: Saturating<F>) -> Saturating<F> {
+                Saturating((self.0).$saturating(other.0))
+            }
+        }
+        impl<'a, F: Fixed> $Op<&'a Saturating<F>> for Saturating<F> {
+            type Output = Saturating<F>;
+            #[inline]
+            fn $op(self, other: &Saturating<F>) -> Saturating<F> {
+                Saturating((self.0).$saturating(other.0))
+            }
+        }
+        impl<'a, 'b, F: Fixed> $Op<&'a Saturating<F>> for &'b Saturating<F> {
+            type Output = Saturating<F>;
+            #[inline]
+            fn $op(self, other: &Saturating<F>) -> Saturating<F> {
+                Saturating((self.0).$saturating(other.0))
+            }
+        }
+        impl<F: Fixed> $OpAssign<Saturating<F>> for Saturating<F> {
+            #[inline]
+            fn $op_assign(&mut self, other: Saturating<F>) {
+                self.0 = (self.0).$saturating(other.0);
+            }
+        }
+        impl<'a, F: Fixed> $OpAssign<&'a Saturating<F>> for Saturating<F> {
+            #[inline]
+            fn $op_assign(&mut self, other: &Saturating<F>) {
+                self.0 = (self.0).$saturating(other.0);
+            }
+        }
+    };
+}
+
+impl<F: Fixed> Neg for Saturating<F> {
+    type Output = Saturating<F>;
+    #[inline]
+    fn neg(self) -> Saturating<F> {
+        Saturating((self.0).saturating_neg())
+    }
+}
+
+impl<F: Fixed> Neg for &Saturating<F> {
+    type Output = Saturating<F>;
+    #[inline]
+    fn neg(self) -> Saturating<F> {
+        Saturating((self.0).saturating_neg())
+    }
+}
+op! { saturating_add, Add add, AddAssign add_assign }
+op! { saturating_sub, Sub sub, SubAssign sub_assign }
+op! { saturating_mul, Mul mul, MulAssign mul_assign }
+op! { saturating_div, Div div, DivAssign div_assign }
+
+impl<F: Fixed> Sum<Saturating<F>> for Saturating<F> {
+    fn sum<I>(iter: I) -> Saturating<F>
+    where
+        I: Iterator<Item = Saturating<F>>,
+    {
+        iter.fold(Saturating(F::from_num(0)), Add::add)
+    }
+}
+
+impl<'a, F: 'a + Fixed> Sum<&'a Saturating<F>> for Saturating<F> {
+    fn sum<I>(iter: I) -> Saturating<F>
+    where
+        I: Iterator<Item = &'a Saturating<F>>,
+    {
+        iter.fold(Saturating(F::from_num(0)), Add::add)
+    }
+}
+
+impl<F: Fixed> Product<Saturating<F>> for Saturating<F> {
+    fn product<I>(mut iter: I) -> Saturating<F>
+    where
+        I: Iterator<Item = Saturating<F>>,
+    {
+        match iter.next() {
+            None => Saturating(1.saturating_to_fixed()),
+            Some(first) => iter.fold(first, Mul::mul),
+        }
+    }
+}
+
+impl<'a, F: 'a + Fixed> Product<&'a Saturating<F>> for Saturating<F> {
+    fn product<I>(mut iter: I) -> Saturating<F>
+    where
+        I: Iterator<Item = &'a Saturating<F>>,
+    {
+        match iter.next() {
+            None => Saturating(1.saturating_to_fixed()),
+            Some(first) => iter.fold(*first, Mul::mul),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{types::*, Saturating};
+    use std::format;
+
+    #[test]
+    fn saturating_ops() {
+        let max = Saturating(I8F8::MAX);
+        let min = Saturating(I8F8::MIN);
+        let delta = Saturating(I8F8::DELTA);
+        assert_eq!(max + delta, max);
+        assert_eq!(min - delta, min);
+        assert_eq!(-min, max);
+        assert_eq!(
+            Saturating(I8F8::from_num(100)) + Saturating(I8F8::from_num(100)),
+            max
+        );
+        assert_eq!(
+            Saturating(I8F8::from_num(-100)) * Saturating(I8F8::from_num(3)),
+            min
+        );
+        assert_eq!(
+            Saturating(I8F8::from_num(1.5)) - Saturating(I8F8::from_num(2)),
+            Saturating(I8F8::from_num(-0.5))
+        );
+
+        let big = Saturating(U16F16::from_num(60000));
+        assert_eq!(big * big, Saturating(U16F16::MAX));
+        assert_eq!(-big, Saturating(U16F16::from_num(0)));
+        assert_eq!(
+            Saturating(U16F16::from_num(1)) - big,
+            Saturating(U16F16::from_num(0))
+        );
+        assert_eq!(
+            Saturating(U16F16::from_num(60000)) / Saturating(U16F16::from_num(0.5)),
+            Saturating(U16F16::MAX)
+        );
+
+        let mut acc = Saturating(I16F16::from_num(32000));
+        acc += Saturating(I16F16::from_num(1000));
+        assert_eq!(acc, Saturating(I16F16::MAX));
+        acc -= Saturating(I16F16::from_num(0.5));
+        assert!(acc < Saturating(I16F16::MAX));
+        assert!(acc > Saturating(I16F16::from_num(32767)));
+    }
+
+    #[test]
+    fn saturating_sum_product() {
+        let samples = [
+            Saturating(I8F8::from_num(100)),
+            Saturating(I8F8::from_num(100)),
+            Saturating(I8F8::from_num(-100)),
+        ];
+        // clips at 127.996 before the negative sample is added
+        let clipped = Saturating(I8F8::MAX - I8F8::from_num(100));
+        assert_eq!(samples.iter().sum::<Saturating<I8F8>>(), clipped);
+        assert_eq!(samples.iter().copied().sum::<Saturating<I8F8>>(), clipped);
+        assert_eq!(
+            samples.iter().product::<Saturating<I8F8>>(),
+            Saturating(I8F8::MIN)
+        );
+        assert_eq!(
+            [].iter().product::<Saturating<I8F8>>(),
+            Saturating(I8F8::from_num(1))
+        );
+    }
+
+    #[test]
+    fn saturating_fmt_parse() {
+        let s = Saturating(I16F16::from_num(-1.5));
+        assert_eq!(format!("{}", s), "-1.5");
+        assert_eq!(format!("{:?}", s), "I16F16(-1.5)");
+        assert_eq!("300".parse::<Saturating<U8F0>>(), Ok(Saturating(U8F0::MAX)));
+        assert_eq!(Saturating::<U8F0>::from_num(-3), Saturating(U8F0::MIN));
+        assert_eq!(Saturating(I16F16::from_num(300.5)).to_num::<i8>(), 127);
+    }
+}
@@ -13,12 +13,16 @@
 // <https://www.apache.org/licenses/LICENSE-2.0> and
 // <https://opensource.org/licenses/MIT>.
 
+#[cfg(feature = "serde-str")]
+use crate::ParseFixedError;
 use crate::{
     types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8},
     FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
     FixedU8, Wrapping,
 };
 use core::fmt::{Formatter, Result as FmtResult};
+#[cfg(feature = "serde-str")]
+use core::{marker::PhantomData, str::FromStr};
 use serde::{
     de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor},
     ser::{Serialize, SerializeStruct, Serializer},
@@ -28,6 +32,12 @@ macro_rules! serde_fixed {
     ($Fixed:ident($LeEqU:ident) is $TBits:ident name $Name:expr) => {
         impl<Frac: $LeEqU> Serialize for $Fixed<Frac> {
             fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                #[cfg(feature = "serde-str")]
+                {
+                    if serializer.is_human_readable() {
+                        return serializer.collect_str(self);
+                    }
+                }
                 let bits = self.to_bits();
                 let mut state = serializer.serialize_struct($Name, 1)?;
                 state.serialize_field("bits", &bits)?;
@@ -42,6 +52,13 @@ macro_rules! serde_fixed {
 
         impl<'de, Frac: $LeEqU> Deserialize<'de> for $Fixed<Frac> {
             fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                #[cfg(feature = "serde-str")]
+                {
+                    if deserializer.is_human_readable() {
+                        return deserializer.deserialize_str(StrVisitor(PhantomData));
+                    }
+                }
+
                 struct FixedVisitor;
 
                 impl<'de> Visitor<'de> for FixedVisitor {
@@ -102,6 +119,22 @@ serde_fixed! { FixedU128(LeEqU128) is u128 name "FixedU128" }
 
 const FIELDS: &[&str] = &["bits"];
 
+#[cfg(feature = "serde-str")]
+struct StrVisitor<F>(PhantomData<F>);
+
+#[cfg(feature = "serde-str")]
+impl<'de, F: FromStr<Err = ParseFixedError>> Visitor<'de> for StrVisitor<F> {
+    type Value = F;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str("a string containing a fixed-point number")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<F, E> {
+        value.parse().map_err(de::Error::custom)
+    }
+}
+
 enum Field {
     Bits,
 }
@@ -128,3 +161,64 @@ impl<'de> Deserialize<'de> for Field {
         deserializer.deserialize_identifier(FieldVisitor)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        types::{I16F16, I8F8, U0F128, U8F8},
+        Wrapping,
+    };
+
+    #[test]
+    fn compact() {
+        let val = I16F16::from_num(-3.25);
+        let encoded = bincode::serialize(&val).unwrap();
+        assert_eq!(encoded, val.to_bits().to_le_bytes());
+        assert_eq!(bincode::deserialize::<I16F16>(&encoded).unwrap(), val);
+        let val = U0F128::from_bits(!0 / 3);
+        let encoded = bincode::serialize(&val).unwrap();
+        assert_eq!(bincode::deserialize::<U0F128>(&encoded).unwrap(), val);
+        let val = Wrapping(U8F8::from_num(1.5));
+        let encoded = bincode::serialize(&val).unwrap();
+        assert_eq!(
+            bincode::deserialize::<Wrapping<U8F8>>(&encoded).unwrap(),
+            val
+        );
+    }
+
+    #[cfg(not(feature = "serde-str"))]
+    #[test]
+    fn human_readable() {
+        let val = I8F8::from_num(3.5);
+        let json = serde_json::to_string(&val).unwrap();
+        assert_eq!(json, r#"{"bits":896}"#);
+        assert_eq!(serde_json::from_str::<I8F8>(&json).unwrap(), val);
+    }
+
+    #[cfg(feature = "serde-str")]
+    #[test]
+    fn human_readable() {
+        use std::string::ToString;
+
+        let val = I8F8::from_num(2.75);
+        let json = serde_json::to_string(&val).unwrap();
+        assert_eq!(json, r#""2.75""#);
+        assert_eq!(serde_json::from_str::<I8F8>(&json).unwrap(), val);
+        let val = Wrapping(I16F16::from_num(-0.5));
+        let json = serde_json::to_string(&val).unwrap();
+        assert_eq!(json, r#""-0.5""#);
+        assert_eq!(
+            serde_json::from_str::<Wrapping<I16F16>>(&json).unwrap(),
+            val
+        );
+        let val = U0F128::from_bits(!0 / 3);
+        let json = serde_json::to_string(&val).unwrap();
+        assert_eq!(serde_json::from_str::<U0F128>(&json).unwrap(), val);
+
+        let err = serde_json::from_str::<I8F8>(r#""1x""#).unwrap_err();
+        assert!(err.to_string().starts_with("invalid digit found in string"));
+        let err = serde_json::from_str::<I8F8>(r#""128""#).unwrap_err();
+        assert!(err.to_string().starts_with("overflow"));
+        assert!(serde_json::from_str::<I8F8>("896").is_err());
+    }
+}
@@ -18,15 +18,20 @@ This module contains traits.
 */
 
 use crate::{
-    helpers::{FloatHelper, FloatKind, FromFloatHelper, IntHelper, Sealed, Widest},
+    helpers::{FloatHelper, FloatKind, FromFloatHelper, IntHelper, Round, Sealed, Widest},
     types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8, Unsigned},
     FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
     FixedU8, ParseFixedError,
 };
 use core::{
+    cmp::Ordering,
     fmt::{Binary, Debug, Display, LowerHex, Octal, UpperHex},
     hash::Hash,
     mem,
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+    },
     ops::{
         Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div,
         DivAssign, Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub,
@@ -216,6 +221,8 @@ where
     Self: Add<Output = Self> + AddAssign + Sub<Output = Self> + SubAssign,
     Self: Mul<Output = Self> + MulAssign + Div<Output = Self> + DivAssign,
     Self: Rem<Output = Self> + RemAssign,
+    Self: Add<<Self as Fixed>::Bits, Output = Self> + AddAssign<<Self as Fixed>::Bits>,
+    Self: Sub<<Self as Fixed>::Bits, Output = Self> + SubAssign<<Self as Fixed>::Bits>,
     Self: Mul<<Self as Fixed>::Bits, Output = Self> + MulAssign<<Self as Fixed>::Bits>,
     Self: Div<<Self as Fixed>::Bits, Output = Self> + DivAssign<<Self as Fixed>::Bits>,
     Self: Rem<<Self as Fixed>::Bits, Output = Self> + RemAssign<<Self as Fixed>::Bits>,
@@ -231,10 +238,39 @@ where
     Self: Sealed,
 {
     /// The primitive integer underlying type.
-    type Bits;
+    ///
+    /// This is always a primitive integer, so generic code can copy,
+    /// compare, hash and format the bits without further bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{traits::Fixed, types::I16F16};
+    /// fn round_trip<F: Fixed>(x: F) -> F {
+    ///     let bits: F::Bits = x.to_bits();
+    ///     assert_eq!(bits, x.to_bits());
+    ///     F::from_bits(bits)
+    /// }
+    /// let x = I16F16::from_num(-2.75);
+    /// assert_eq!(round_trip(x), x);
+    /// ```
+    type Bits: Copy + Default + Hash + Ord + Debug + Display + Binary + Octal + LowerHex + UpperHex;
 
     /// A byte array with the same size as the type.
-    type Bytes;
+    ///
+    /// The array can be accessed as a slice, so generic code can
+    /// serialize any fixed-point number.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{traits::Fixed, types::U8F8};
+    /// fn le_len<F: Fixed>(x: F) -> usize {
+    ///     x.to_le_bytes().as_ref().len()
+    /// }
+    /// assert_eq!(le_len(U8F8::from_num(1)), 2);
+    /// ```
+    type Bytes: Copy + Default + Hash + Ord + Debug + AsRef<[u8]> + AsMut<[u8]>;
 
     /// The number of fractional bits.
     ///
@@ -246,12 +282,173 @@ where
     /// [`frac_nbits`]: #tymethod.frac_nbits
     type Frac: Unsigned;
 
+    /// The unsigned fixed-point type with the same number of integer
+    /// and fractional bits, for example [`U16F16`] for [`I16F16`]
+    /// and for [`U16F16`] itself.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{
+    ///     traits::Fixed,
+    ///     types::{I16F16, U16F16},
+    /// };
+    /// fn magnitude<F: Fixed>(x: F) -> F::Unsigned {
+    ///     x.unsigned_abs()
+    /// }
+    /// let m: U16F16 = magnitude(I16F16::from_num(-2.5));
+    /// assert_eq!(m, U16F16::from_num(2.5));
+    /// ```
+    ///
+    /// [`I16F16`]: ../types/type.I16F16.html
+    /// [`U16F16`]: ../types/type.U16F16.html
+    type Unsigned: FixedUnsigned<
+        Frac = Self::Frac,
+        Unsigned = Self::Unsigned,
+        Signed = Self::Signed,
+    >;
+
+    /// The signed fixed-point type with the same number of integer
+    /// and fractional bits, for example [`I16F16`] for [`U16F16`]
+    /// and for [`I16F16`] itself.
+    ///
+    /// [`I16F16`]: ../types/type.I16F16.html
+    /// [`U16F16`]: ../types/type.U16F16.html
+    type Signed: FixedSigned<Frac = Self::Frac, Unsigned = Self::Unsigned, Signed = Self::Signed>;
+
+    /// The number of integer bits.
+    ///
+    /// This is the same as <code>[int_nbits][`int_nbits`]()</code>,
+    /// but can be used in constant expressions.
+    ///
+    /// [`int_nbits`]: #tymethod.int_nbits
+    const INT_NBITS: u32;
+
+    /// The number of fractional bits.
+    ///
+    /// This is the same as <code>[frac_nbits][`frac_nbits`]()</code>,
+    /// but can be used in constant expressions.
+    ///
+    /// [`frac_nbits`]: #tymethod.frac_nbits
+    const FRAC_NBITS: u32;
+
+    /// The total number of bits, that is the sum of
+    /// [`INT_NBITS`][`INT_NBITS`] and [`FRAC_NBITS`][`FRAC_NBITS`].
+    ///
+    /// [`FRAC_NBITS`]: #associatedconstant.FRAC_NBITS
+    /// [`INT_NBITS`]: #associatedconstant.INT_NBITS
+    const NBITS: u32;
+
     /// Returns the smallest value that can be represented.
     fn min_value() -> Self;
 
     /// Returns the largest value that can be represented.
     fn max_value() -> Self;
 
+    /// Returns the identity element of a minimum reduction, which is
+    /// the largest value that can be represented.
+    #[inline]
+    fn identity_for_min() -> Self {
+        Self::max_value()
+    }
+
+    /// Returns the identity element of a maximum reduction, which is
+    /// the smallest value that can be represented.
+    #[inline]
+    fn identity_for_max() -> Self {
+        Self::min_value()
+    }
+
+    /// Returns the minimum of the values, or
+    /// <code>[identity_for_min][`identity_for_min`]()</code> if there are
+    /// no values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{traits::Fixed, types::I16F16};
+    ///
+    /// fn smallest<F: Fixed>(values: &[F]) -> F {
+    ///     F::reduce_min(values.iter().cloned())
+    /// }
+    ///
+    /// let values = [I16F16::from_num(1.5), I16F16::from_num(-2.25), I16F16::from_num(3)];
+    /// assert_eq!(smallest(&values), I16F16::from_num(-2.25));
+    /// assert_eq!(smallest::<I16F16>(&[]), I16F16::max_value());
+    /// ```
+    ///
+    /// [`identity_for_min`]: #method.identity_for_min
+    #[inline]
+    fn reduce_min<I>(values: I) -> Self
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        values.into_iter().fold(Self::identity_for_min(), Ord::min)
+    }
+
+    /// Returns the maximum of the values, or
+    /// <code>[identity_for_max][`identity_for_max`]()</code> if there are
+    /// no values.
+    ///
+    /// [`identity_for_max`]: #method.identity_for_max
+    #[inline]
+    fn reduce_max<I>(values: I) -> Self
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        values.into_iter().fold(Self::identity_for_max(), Ord::max)
+    }
+
+    /// Returns the minimum and maximum of the values in a slice, or
+    /// [`None`] if the slice is empty.
+    ///
+    /// Both extremes are found in a single pass, comparing values in
+    /// pairs so that about 3<i>n</i>/2 comparisons are made.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{traits::Fixed, types::I16F16};
+    /// let values = [
+    ///     I16F16::from_num(1.5),
+    ///     I16F16::from_num(-2.25),
+    ///     I16F16::from_num(3),
+    ///     I16F16::from_num(-0.5),
+    /// ];
+    /// let (min, max) = I16F16::min_max(&values).unwrap();
+    /// assert_eq!((min, max), (I16F16::from_num(-2.25), I16F16::from_num(3)));
+    /// assert_eq!(I16F16::min_max(&values[..1]), Some((values[0], values[0])));
+    /// assert_eq!(I16F16::min_max(&[]), None);
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    fn min_max(slice: &[Self]) -> Option<(Self, Self)> {
+        let (first, rest) = slice.split_first()?;
+        let (mut min, mut max) = (*first, *first);
+        let mut pairs = rest.chunks_exact(2);
+        for pair in &mut pairs {
+            let (lo, hi) = if pair[0] <= pair[1] {
+                (pair[0], pair[1])
+            } else {
+                (pair[1], pair[0])
+            };
+            if lo < min {
+                min = lo;
+            }
+            if hi > max {
+                max = hi;
+            }
+        }
+        if let Some(&last) = pairs.remainder().first() {
+            if last < min {
+                min = last;
+            } else if last > max {
+                max = last;
+            }
+        }
+        Some((min, max))
+    }
+
     /// Returns the number of integer bits.
     fn int_nbits() -> u32;
 
@@ -278,6 +475,36 @@ where
     /// array in native endian.
     fn from_ne_bytes(bytes: Self::Bytes) -> Self;
 
+    /// Creates a fixed-point number from its representation as a byte
+    /// slice in big endian.
+    ///
+    /// Returns [`None`] if the length of the slice is not the size of
+    /// the type.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    fn from_be_byte_slice(bytes: &[u8]) -> Option<Self>;
+
+    /// Creates a fixed-point number from its representation as a byte
+    /// slice in little endian.
+    ///
+    /// Returns [`None`] if the length of the slice is not the size of
+    /// the type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{traits::Fixed, types::I16F16};
+    /// fn decode<F: Fixed>(bytes: &[u8]) -> Option<F> {
+    ///     F::from_le_byte_slice(bytes)
+    /// }
+    /// let x = I16F16::from_num(-2.75);
+    /// assert_eq!(decode::<I16F16>(&x.to_le_bytes()), Some(x));
+    /// assert_eq!(decode::<I16F16>(&[0, 1]), None);
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    fn from_le_byte_slice(bytes: &[u8]) -> Option<Self>;
+
     /// Returns the memory representation of this fixed-point number
     /// as a byte array in big-endian byte order.
     fn to_be_bytes(self) -> Self::Bytes;
@@ -620,6 +847,25 @@ where
     /// Shifts to the right by `n` bits, wrapping the truncated bits to the left end.
     fn rotate_right(self, n: u32) -> Self;
 
+    /// Reverses the byte order of the bit pattern.
+    fn swap_bytes(self) -> Self;
+
+    /// Reverses the order of the bits of the bit pattern.
+    fn reverse_bits(self) -> Self;
+
+    /// Returns the absolute value using an unsigned type without
+    /// any wrapping or panicking.
+    fn unsigned_abs(self) -> Self::Unsigned;
+
+    /// Returns the absolute value of the difference between `self`
+    /// and `other` using an unsigned type without any wrapping or
+    /// panicking.
+    fn abs_diff(self, other: Self) -> Self::Unsigned;
+
+    /// Returns the midpoint of `self` and `other`, rounding towards
+    /// zero, without overflowing.
+    fn midpoint(self, other: Self) -> Self;
+
     /// Euclidean division by an integer.
     ///
     /// # Panics
@@ -627,6 +873,15 @@ where
     /// Panics if the divisor is zero or if the division results in overflow.
     fn div_euclid(self, rhs: Self) -> Self;
 
+    /// Division with remainder. Returns the quotient rounded towards
+    /// zero, as a fixed-point number with an integer value, and the
+    /// remainder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero or if the quotient overflows.
+    fn div_rem(self, rhs: Self) -> (Self, Self);
+
     /// Remainder for Euclidean division.
     ///
     /// # Panics
@@ -634,6 +889,34 @@ where
     /// Panics if the divisor is zero.
     fn rem_euclid(self, rhs: Self) -> Self;
 
+    /// Multiplication by an integer.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the
+    /// result overflows. When debug assertions are not enabled, the
+    /// wrapped value can be returned, but it is not considered a
+    /// breaking change if in the future it panics; if wrapping is
+    /// required use [`wrapping_mul_int`] instead.
+    ///
+    /// [`wrapping_mul_int`]: #tymethod.wrapping_mul_int
+    fn mul_int(self, rhs: Self::Bits) -> Self;
+
+    /// Division by an integer, truncating towards zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero or if the division results in overflow.
+    fn div_int(self, rhs: Self::Bits) -> Self;
+
+    /// Division by an integer, rounding to the nearest with ties
+    /// rounded away from zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero or if the division results in overflow.
+    fn div_int_round(self, rhs: Self::Bits) -> Self;
+
     /// Euclidean division by an integer.
     ///
     /// # Panics
@@ -648,6 +931,16 @@ where
     /// Panics if the divisor is zero or if the division results in overflow.
     fn rem_euclid_int(self, rhs: Self::Bits) -> Self;
 
+    /// Returns the smallest multiple of `rhs` that is ≥ `self` if
+    /// `rhs` is positive, or the largest multiple of `rhs` that is
+    /// ≤ `self` if `rhs` is negative.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero. When debug assertions are enabled,
+    /// also panics if the result overflows.
+    fn next_multiple_of(self, rhs: Self) -> Self;
+
     /// Checked negation. Returns the negated value, or [`None`] on overflow.
     ///
     /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
@@ -727,6 +1020,14 @@ where
     /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
     fn checked_rem_euclid_int(self, rhs: Self::Bits) -> Option<Self>;
 
+    /// Checked next multiple of `rhs`. Returns the smallest multiple
+    /// of `rhs` that is ≥ `self` if `rhs` is positive, or the
+    /// largest multiple of `rhs` that is ≤ `self` if `rhs` is
+    /// negative, returning [`None`] if `rhs` is zero or on overflow.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    fn checked_next_multiple_of(self, rhs: Self) -> Option<Self>;
+
     /// Checked shift left. Returns the shifted number, or [`None`] if
     /// `rhs` ≥ the number of bits.
     ///
@@ -768,6 +1069,13 @@ where
     /// Saturating multiplication by an integer. Returns the product, saturating on overflow.
     fn saturating_mul_int(self, rhs: Self::Bits) -> Self;
 
+    /// Saturating division by an integer. Returns the quotient, saturating on overflow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    fn saturating_div_int(self, rhs: Self::Bits) -> Self;
+
     /// Wrapping negation. Returns the negated value, wrapping on overflow.
     fn wrapping_neg(self) -> Self;
 
@@ -972,6 +1280,21 @@ where
     /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
     fn overflowing_shr(self, rhs: u32) -> (Self, bool);
 
+    /// Returns [`true`][`bool`] if the addition would overflow.
+    ///
+    /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+    fn add_overflows(self, rhs: Self) -> bool;
+
+    /// Returns [`true`][`bool`] if the subtraction would overflow.
+    ///
+    /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+    fn sub_overflows(self, rhs: Self) -> bool;
+
+    /// Returns [`true`][`bool`] if the multiplication would overflow.
+    ///
+    /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+    fn mul_overflows(self, rhs: Self) -> bool;
+
     /// Remainder for division by an integer.
     ///
     /// # Panics
@@ -1103,6 +1426,22 @@ pub trait FixedUnsigned: Fixed {
     ///
     /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
     fn checked_next_power_of_two(self) -> Option<Self>;
+
+    /// Returns the smallest power of two that is ≥ `self`, saturating to the
+    /// maximum value if the next power of two is too large to represent.
+    fn saturating_next_power_of_two(self) -> Self;
+
+    /// Returns the smallest power of two that is ≥ `self`, or zero if the
+    /// next power of two is too large to represent.
+    fn wrapping_next_power_of_two(self) -> Self;
+
+    /// Returns a [tuple] of the smallest power of two that is ≥ `self` and a
+    /// [`bool`] indicating whether an overflow has occurred. On overflow,
+    /// zero is returned.
+    ///
+    /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+    /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+    fn overflowing_next_power_of_two(self) -> (Self, bool);
 }
 
 /// This trait provides infallible conversions that might be lossy.
@@ -1229,6 +1568,20 @@ pub trait FromFixed {
     fn overflowing_from_fixed<F: Fixed>(src: F) -> (Self, bool)
     where
         Self: Sized;
+
+    #[doc(hidden)]
+    /// Converts from a fixed-point number using the given rounding
+    /// mode, wrapping on overflow. The default ignores the rounding
+    /// mode, which is correct for floating-point numbers as they
+    /// already round to the nearest.
+    #[inline]
+    fn private_overflowing_from_fixed_round<F: Fixed>(src: F, round: Round) -> (Self, bool)
+    where
+        Self: Sized,
+    {
+        let _ = round;
+        Self::overflowing_from_fixed(src)
+    }
 }
 
 /// This trait provides checked conversions to fixed-point numbers.
@@ -1318,6 +1671,19 @@ pub trait ToFixed {
     /// [finite]: https://doc.rust-lang.org/nightly/std/primitive.f64.html#method.is_finite
     /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
     fn overflowing_to_fixed<F: Fixed>(self) -> (F, bool);
+
+    #[doc(hidden)]
+    /// Converts to a fixed-point number using the given rounding
+    /// mode, wrapping on overflow. The default is correct for types
+    /// whose conversion is always exact, such as integers.
+    #[inline]
+    fn private_overflowing_to_fixed_round<F: Fixed>(self, round: Round) -> (F, bool)
+    where
+        Self: Sized,
+    {
+        let _ = round;
+        self.overflowing_to_fixed()
+    }
 }
 
 impl ToFixed for bool {
@@ -1440,6 +1806,15 @@ macro_rules! impl_int {
                 let (repr_fixed, overflow) = FromFixed::overflowing_from_fixed(src);
                 ($Int::from_repr_fixed(repr_fixed), overflow)
             }
+
+            #[inline]
+            fn private_overflowing_from_fixed_round<F: Fixed>(
+                src: F,
+                round: Round,
+            ) -> (Self, bool) {
+                let (repr_fixed, overflow) = src.private_overflowing_to_fixed_round(round);
+                ($Int::from_repr_fixed(repr_fixed), overflow)
+            }
         }
 
         impl ToFixed for $Int {
@@ -1509,6 +1884,75 @@ impl_int! { u64 }
 impl_int! { u128 }
 impl_int! { usize }
 
+macro_rules! impl_nonzero {
+    ($NonZero:ident) => {
+        impl ToFixed for $NonZero {
+            /// Converts a non-zero integer to a fixed-point number.
+            ///
+            /// # Panics
+            ///
+            /// When debug assertions are enabled, panics if the value
+            /// does not fit. When debug assertions are not enabled,
+            /// the wrapped value can be returned, but it is not
+            /// considered a breaking change if in the future it
+            /// panics; if wrapping is required use
+            /// [`wrapping_to_fixed`] instead.
+            ///
+            /// [`wrapping_to_fixed`]: #method.wrapping_to_fixed
+            #[inline]
+            fn to_fixed<F: Fixed>(self) -> F {
+                ToFixed::to_fixed(self.get())
+            }
+
+            /// Converts a non-zero integer to a fixed-point number if it fits, otherwise returns [`None`].
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            fn checked_to_fixed<F: Fixed>(self) -> Option<F> {
+                ToFixed::checked_to_fixed(self.get())
+            }
+
+            /// Converts a non-zero integer to a fixed-point number, saturating if it does not fit.
+            #[inline]
+            fn saturating_to_fixed<F: Fixed>(self) -> F {
+                ToFixed::saturating_to_fixed(self.get())
+            }
+
+            /// Converts a non-zero integer to a fixed-point number, wrapping if it does not fit.
+            #[inline]
+            fn wrapping_to_fixed<F: Fixed>(self) -> F {
+                ToFixed::wrapping_to_fixed(self.get())
+            }
+
+            /// Converts a non-zero integer to a fixed-point number.
+            ///
+            /// Returns a [tuple] of the fixed-point number and a [`bool`]
+            /// indicating whether an overflow has occurred. On overflow, the
+            /// wrapped value is returned.
+            ///
+            /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+            /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+            #[inline]
+            fn overflowing_to_fixed<F: Fixed>(self) -> (F, bool) {
+                ToFixed::overflowing_to_fixed(self.get())
+            }
+        }
+    };
+}
+
+impl_nonzero! { NonZeroI8 }
+impl_nonzero! { NonZeroI16 }
+impl_nonzero! { NonZeroI32 }
+impl_nonzero! { NonZeroI64 }
+impl_nonzero! { NonZeroI128 }
+impl_nonzero! { NonZeroIsize }
+impl_nonzero! { NonZeroU8 }
+impl_nonzero! { NonZeroU16 }
+impl_nonzero! { NonZeroU32 }
+impl_nonzero! { NonZeroU64 }
+impl_nonzero! { NonZeroU128 }
+impl_nonzero! { NonZeroUsize }
+
 macro_rules! impl_float {
     ($Float:ty) => {
         impl FromFixed for $Float {
@@ -1678,6 +2122,13 @@ macro_rules! impl_float {
                 let helper = FromFloatHelper { kind };
                 F::private_overflowing_from_float_helper(helper)
             }
+
+            #[inline]
+            fn private_overflowing_to_fixed_round<F: Fixed>(self, round: Round) -> (F, bool) {
+                let kind = self.to_float_kind_round(F::frac_nbits(), F::int_nbits(), round);
+                let helper = FromFloatHelper { kind };
+                F::private_overflowing_from_float_helper(helper)
+            }
         }
     };
 }
@@ -1717,13 +2168,21 @@ macro_rules! trait_delegate {
 }
 
 macro_rules! impl_fixed {
-    ($Fixed:ident, $LeEqU:ident, $Bits:ident, $Signedness:tt) => {
+    (
+        $Fixed:ident($IFixed:ident, $UFixed:ident), $LeEqU:ident, $Bits:ident,
+        $Signedness:tt
+    ) => {
         impl<Frac: $LeEqU> FixedOptionalFeatures for $Fixed<Frac> {}
 
         impl<Frac: $LeEqU> Fixed for $Fixed<Frac> {
             type Bits = $Bits;
             type Bytes = [u8; mem::size_of::<$Bits>()];
             type Frac = Frac;
+            type Unsigned = $UFixed<Frac>;
+            type Signed = $IFixed<Frac>;
+            const INT_NBITS: u32 = Self::NBITS - Frac::U32;
+            const FRAC_NBITS: u32 = Frac::U32;
+            const NBITS: u32 = mem::size_of::<$Bits>() as u32 * 8;
             trait_delegate! { fn min_value() -> Self }
             trait_delegate! { fn max_value() -> Self }
             trait_delegate! { fn int_nbits() -> u32 }
@@ -1733,6 +2192,24 @@ macro_rules! impl_fixed {
             trait_delegate! { fn from_be_bytes(bits: Self::Bytes) -> Self }
             trait_delegate! { fn from_le_bytes(bits: Self::Bytes) -> Self }
             trait_delegate! { fn from_ne_bytes(bits: Self::Bytes) -> Self }
+            #[inline]
+            fn from_be_byte_slice(bytes: &[u8]) -> Option<Self> {
+                let mut array = Self::Bytes::default();
+                if bytes.len() != array.len() {
+                    return None;
+                }
+                array.copy_from_slice(bytes);
+                Some(Self::from_be_bytes(array))
+            }
+            #[inline]
+            fn from_le_byte_slice(bytes: &[u8]) -> Option<Self> {
+                let mut array = Self::Bytes::default();
+                if bytes.len() != array.len() {
+                    return None;
+                }
+                array.copy_from_slice(bytes);
+                Some(Self::from_le_bytes(array))
+            }
             trait_delegate! { fn to_be_bytes(self) -> Self::Bytes }
             trait_delegate! { fn to_le_bytes(self) -> Self::Bytes }
             trait_delegate! { fn to_ne_bytes(self) -> Self::Bytes }
@@ -1814,10 +2291,20 @@ macro_rules! impl_fixed {
             trait_delegate! { fn trailing_zeros(self) -> u32 }
             trait_delegate! { fn rotate_left(self, n: u32) -> Self }
             trait_delegate! { fn rotate_right(self, n: u32) -> Self }
+            trait_delegate! { fn swap_bytes(self) -> Self }
+            trait_delegate! { fn reverse_bits(self) -> Self }
+            trait_delegate! { fn unsigned_abs(self) -> Self::Unsigned }
+            trait_delegate! { fn abs_diff(self, other: Self) -> Self::Unsigned }
+            trait_delegate! { fn midpoint(self, other: Self) -> Self }
             trait_delegate! { fn div_euclid(self, rhs: Self) -> Self }
+            trait_delegate! { fn div_rem(self, rhs: Self) -> (Self, Self) }
             trait_delegate! { fn rem_euclid(self, rhs: Self) -> Self }
+            trait_delegate! { fn mul_int(self, rhs: Self::Bits) -> Self }
+            trait_delegate! { fn div_int(self, rhs: Self::Bits) -> Self }
+            trait_delegate! { fn div_int_round(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn div_euclid_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn rem_euclid_int(self, rhs: Self::Bits) -> Self }
+            trait_delegate! { fn next_multiple_of(self, rhs: Self) -> Self }
             trait_delegate! { fn checked_neg(self) -> Option<Self> }
             trait_delegate! { fn checked_add(self, rhs: Self) -> Option<Self> }
             trait_delegate! { fn checked_sub(self, rhs: Self) -> Option<Self> }
@@ -1831,6 +2318,7 @@ macro_rules! impl_fixed {
             trait_delegate! { fn checked_rem_int(self, rhs: Self::Bits) -> Option<Self> }
             trait_delegate! { fn checked_div_euclid_int(self, rhs: Self::Bits) -> Option<Self> }
             trait_delegate! { fn checked_rem_euclid_int(self, rhs: Self::Bits) -> Option<Self> }
+            trait_delegate! { fn checked_next_multiple_of(self, rhs: Self) -> Option<Self> }
             trait_delegate! { fn checked_shl(self, rhs: u32) -> Option<Self> }
             trait_delegate! { fn checked_shr(self, rhs: u32) -> Option<Self> }
             trait_delegate! { fn saturating_neg(self) -> Self }
@@ -1840,6 +2328,7 @@ macro_rules! impl_fixed {
             trait_delegate! { fn saturating_div(self, rhs: Self) -> Self }
             trait_delegate! { fn saturating_div_euclid(self, rhs: Self) -> Self }
             trait_delegate! { fn saturating_mul_int(self, rhs: Self::Bits) -> Self }
+            trait_delegate! { fn saturating_div_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn wrapping_neg(self) -> Self }
             trait_delegate! { fn wrapping_add(self, rhs: Self) -> Self }
             trait_delegate! { fn wrapping_sub(self, rhs: Self) -> Self }
@@ -1864,6 +2353,9 @@ macro_rules! impl_fixed {
             trait_delegate! { fn overflowing_rem_euclid_int(self, rhs: Self::Bits) -> (Self, bool) }
             trait_delegate! { fn overflowing_shl(self, rhs: u32) -> (Self, bool) }
             trait_delegate! { fn overflowing_shr(self, rhs: u32) -> (Self, bool) }
+            trait_delegate! { fn add_overflows(self, rhs: Self) -> bool }
+            trait_delegate! { fn sub_overflows(self, rhs: Self) -> bool }
+            trait_delegate! { fn mul_overflows(self, rhs: Self) -> bool }
         }
 
         impl<Frac: $LeEqU> FromFixed for $Fixed<Frac> {
@@ -1969,6 +2461,14 @@ macro_rules! impl_fixed {
                 };
                 (Self::from_bits(bits), conv.overflow || new_overflow)
             }
+
+            #[inline]
+            fn private_overflowing_from_fixed_round<F: Fixed>(
+                src: F,
+                round: Round,
+            ) -> (Self, bool) {
+                src.private_overflowing_to_fixed_round(round)
+            }
         }
 
         impl<Frac: $LeEqU> ToFixed for $Fixed<Frac> {
@@ -2020,6 +2520,36 @@ macro_rules! impl_fixed {
             fn overflowing_to_fixed<F: Fixed>(self) -> (F, bool) {
                 FromFixed::overflowing_from_fixed(self)
             }
+
+            #[inline]
+            fn private_overflowing_to_fixed_round<F: Fixed>(self, round: Round) -> (F, bool) {
+                let mut conv = self.private_to_fixed_helper(F::frac_nbits(), F::int_nbits());
+                if conv.dir != Ordering::Equal {
+                    // bits were truncated towards −∞; decide whether to add one lsb
+                    let bits = self.to_bits();
+                    let shift = Self::FRAC_NBITS - F::frac_nbits();
+                    let up = match round {
+                        Round::Floor => false,
+                        Round::Ceil => true,
+                        Round::Trunc => bits.is_negative(),
+                        Round::Nearest => {
+                            // 0 < shift ≤ FRAC_NBITS ≤ NBITS
+                            let half = (bits >> (shift - 1)) & 1 != 0;
+                            let rest = bits & !(!0 << (shift - 1)) != 0;
+                            let odd = match conv.bits {
+                                Widest::Unsigned(bits) => bits & 1 != 0,
+                                Widest::Negative(bits) => bits & 1 != 0,
+                            };
+                            half && (rest || odd)
+                        }
+                    };
+                    if up {
+                        conv = conv.increment(F::int_nbits() + F::frac_nbits());
+                    }
+                }
+                let kind = FloatKind::Finite { neg: false, conv };
+                F::private_overflowing_from_float_helper(FromFloatHelper { kind })
+            }
         }
 
         if_signed! {
@@ -2042,18 +2572,21 @@ macro_rules! impl_fixed {
                 trait_delegate! { fn is_power_of_two(self) -> bool }
                 trait_delegate! { fn next_power_of_two(self) -> Self }
                 trait_delegate! { fn checked_next_power_of_two(self) -> Option<Self> }
+                trait_delegate! { fn saturating_next_power_of_two(self) -> Self }
+                trait_delegate! { fn wrapping_next_power_of_two(self) -> Self }
+                trait_delegate! { fn overflowing_next_power_of_two(self) -> (Self, bool) }
             }
         }
     };
 }
 
-impl_fixed! { FixedI8, LeEqU8, i8, Signed }
-impl_fixed! { FixedI16, LeEqU16, i16, Signed }
-impl_fixed! { FixedI32, LeEqU32, i32, Signed }
-impl_fixed! { FixedI64, LeEqU64, i64, Signed }
-impl_fixed! { FixedI128, LeEqU128, i128, Signed }
-impl_fixed! { FixedU8, LeEqU8, u8, Unsigned }
-impl_fixed! { FixedU16, LeEqU16, u16, Unsigned }
-impl_fixed! { FixedU32, LeEqU32, u32, Unsigned }
-impl_fixed! { FixedU64, LeEqU64, u64, Unsigned }
-impl_fixed! { FixedU128, LeEqU128, u128, Unsigned }
+impl_fixed! { FixedI8(FixedI8, FixedU8), LeEqU8, i8, Signed }
+impl_fixed! { FixedI16(FixedI16, FixedU16), LeEqU16, i16, Signed }
+impl_fixed! { FixedI32(FixedI32, FixedU32), LeEqU32, i32, Signed }
+impl_fixed! { FixedI64(FixedI64, FixedU64), LeEqU64, i64, Signed }
+impl_fixed! { FixedI128(FixedI128, FixedU128), LeEqU128, i128, Signed }
+impl_fixed! { FixedU8(FixedI8, FixedU8), LeEqU8, u8, Unsigned }
+impl_fixed! { FixedU16(FixedI16, FixedU16), LeEqU16, u16, Unsigned }
+impl_fixed! { FixedU32(FixedI32, FixedU32), LeEqU32, u32, Unsigned }
+impl_fixed! { FixedU64(FixedI64, FixedU64), LeEqU64, u64, Unsigned }
+impl_fixed! { FixedU128(FixedI128, FixedU128), LeEqU128, u128, Unsigned }
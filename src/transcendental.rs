@@ -42,6 +42,10 @@ pub const FRAC_PI_4: I9F23 = I9F23::from_bits((consts::PI.to_bits() >> 105) as i
 pub const LOG2_E: I9F23 = I9F23::from_bits((consts::LOG2_E.to_bits() >> 104) as i32);
 /// e
 pub const E: I9F23 = I9F23::from_bits((consts::E.to_bits() >> 103) as i32);
+/// log2(10)
+pub const LOG2_10: I9F23 = I9F23::from_bits((consts::LOG2_10.to_bits() >> 103) as i32);
+/// ln(10)
+pub const LN_10: I9F23 = I9F23::from_bits((consts::LN_10.to_bits() >> 103) as i32);
 
 // generate with
 // ```matlab
@@ -280,7 +284,50 @@ where
     Ok(result)
 }
 
-/// power
+/// linear gain to decibels, 20*log10(operand)
+///
+/// Returns an error for non-positive operands. The accuracy is that of
+/// [`log2`] scaled by 20/log2(10).
+pub fn to_db<S, D>(operand: S) -> Result<D, ()>
+where
+    S: FixedSigned + PartialOrd<ConstType>,
+    D: FixedSigned + PartialOrd<ConstType> + From<S> + From<ConstType>,
+    D::Bits: Copy + ToFixed + AddAssign + BitOrAssign + ShlAssign,
+{
+    let log2 = log2::<S, D>(operand)?;
+    let scaled = if let Some(r) = log2.checked_mul(D::from_num(20)) {
+        r
+    } else {
+        return Err(());
+    };
+    Ok(scaled / D::from(LOG2_10))
+}
+
+/// decibels to linear gain, 10^(db/20)
+///
+/// Computed as e^(db*ln(10)/20), so the accuracy is that of [`exp`].
+/// A value of 0 dB gives exactly 1.
+pub fn from_db<S, D>(db: S) -> Result<D, ()>
+where
+    S: FixedSigned + PartialOrd<ConstType>,
+    D: FixedSigned + PartialOrd<ConstType> + From<S> + From<ConstType>,
+{
+    let db = D::from(db);
+    let exponent = if let Some(r) = db.checked_mul(D::from(LN_10)) {
+        r / D::from_num(20)
+    } else {
+        return Err(());
+    };
+    exp::<D, D>(exponent)
+}
+
+/// power, e^(exponent*ln(operand))
+///
+/// An operand of 0 gives 0, and an operand of 1 or an exponent of 0 gives
+/// exactly 1. Returns an error for negative operands or if the result
+/// overflows. The error of [`ln`] is scaled by the exponent before [`exp`]
+/// adds its own, so the relative error of the result grows roughly with
+/// |exponent*ln(operand)|.
 pub fn pow<S, D>(operand: S, exponent: S) -> Result<D, ()>
 where
     S: FixedSigned + PartialOrd<ConstType>,
@@ -292,7 +339,7 @@ where
     if operand == S::from_num(0) {
         return Ok(D::from_num(0));
     };
-    if exponent == S::from_num(0) {
+    if operand == S::from_num(1) || exponent == S::from_num(0) {
         return Ok(D::from_num(1));
     };
     if exponent == S::from_num(1) {
@@ -304,16 +351,48 @@ where
     } else {
         return Err(());
     };
-    let result: D = if let Ok(r) = exp(r) {
-        r
-    } else {
-        return Err(());
+    exp::<D, D>(r)
+}
+
+/// checked power with fractional exponent, e^(exponent*ln(operand))
+///
+/// This is [`pow`] restricted to positive operands. Returns [`None`] if the
+/// operand is not positive or if the result overflows.
+pub fn checked_powf<S, D>(operand: S, exponent: S) -> Option<D>
+where
+    S: FixedSigned + PartialOrd<ConstType>,
+    D: FixedSigned + PartialOrd<ConstType> + From<S> + From<ConstType>,
+    D::Bits: Copy + ToFixed + AddAssign + BitOrAssign + ShlAssign,
+{
+    if operand <= S::from_num(0) {
+        return None;
     };
-    let (result, oflw) = result.overflowing_to_num::<D>();
-    if oflw {
-        return Err(());
+    pow(operand, exponent).ok()
+}
+
+/// saturating power with fractional exponent, e^(exponent*ln(operand))
+///
+/// Saturates to the maximum value if the result is too large and to zero if
+/// it is too small. See [`pow`] for the accuracy.
+///
+/// # Panics
+///
+/// Panics if the operand is not positive; use [`checked_powf`] instead.
+pub fn powf<S, D>(operand: S, exponent: S) -> D
+where
+    S: FixedSigned + PartialOrd<ConstType>,
+    D: FixedSigned + PartialOrd<ConstType> + From<S> + From<ConstType>,
+    D::Bits: Copy + ToFixed + AddAssign + BitOrAssign + ShlAssign,
+{
+    assert!(operand > S::from_num(0), "non-positive base");
+    if let Some(r) = checked_powf(operand, exponent) {
+        return r;
     };
-    Ok(result)
+    if (operand < S::from_num(1)) == (exponent < S::from_num(0)) {
+        D::max_value()
+    } else {
+        D::from_num(0)
+    }
 }
 
 /// power with integer exponend
@@ -569,6 +648,26 @@ mod tests {
         assert_relative_eq!(result, 102.619e-12, epsilon = 1.0e-12);
     }
 
+    #[test]
+    fn db_works() {
+        type S = I9F23;
+        type D = I32F32;
+
+        assert_eq!(from_db::<S, D>(ZERO).unwrap(), D::from_num(1));
+        let result: f64 = from_db::<S, D>(S::from_num(-6)).unwrap().lossy_into();
+        assert_relative_eq!(result, 0.501187, epsilon = 1.0e-3);
+        let result: f64 = from_db::<S, D>(S::from_num(20)).unwrap().lossy_into();
+        assert_relative_eq!(result, 10.0, epsilon = 1.0e-2);
+
+        assert!(to_db::<S, D>(ZERO).is_err());
+        assert!(to_db::<S, D>(S::from_num(-1)).is_err());
+        assert_eq!(to_db::<S, D>(ONE).unwrap(), ZERO);
+        let result: f64 = to_db::<S, D>(S::from_num(10)).unwrap().lossy_into();
+        assert_relative_eq!(result, 20.0, epsilon = 1.0e-3);
+        let result: f64 = to_db::<S, D>(S::from_num(0.5)).unwrap().lossy_into();
+        assert_relative_eq!(result, -6.020600, epsilon = 1.0e-3);
+    }
+
     #[test]
     fn pow_works() {
         type S = I9F23;
@@ -599,6 +698,47 @@ mod tests {
         assert!(pow::<S, D>(S::from_num(-0.0001), S::from_num(2)).is_err());
     }
 
+    #[test]
+    fn powf_works() {
+        type S = I9F23;
+        type D = I32F32;
+
+        assert_eq!(
+            checked_powf::<S, D>(ONE, S::from_num(3.7)),
+            Some(D::from_num(1))
+        );
+        assert_eq!(
+            checked_powf::<S, D>(S::from_num(3.7), ZERO),
+            Some(D::from_num(1))
+        );
+        assert_eq!(checked_powf::<S, D>(ZERO, TWO), None);
+        assert_eq!(checked_powf::<S, D>(S::from_num(-2), TWO), None);
+        assert_eq!(
+            checked_powf::<S, D>(S::from_num(200), S::from_num(200)),
+            None
+        );
+        assert_eq!(
+            checked_powf::<S, D>(S::from_num(2.9), S::from_num(3.1)),
+            pow::<S, D>(S::from_num(2.9), S::from_num(3.1)).ok()
+        );
+
+        let result: f64 = powf::<S, D>(TWO, S::from_num(0.5)).lossy_into();
+        assert_relative_eq!(result, 2f64.sqrt(), epsilon = 1.0e-4);
+        let result: f64 = powf::<S, D>(TWO, S::from_num(-0.5)).lossy_into();
+        assert_relative_eq!(result, 0.5f64.sqrt(), epsilon = 1.0e-4);
+        let result: f64 = powf::<S, D>(S::from_num(2.9), S::from_num(3.1)).lossy_into();
+        assert_relative_eq!(result, 27.129, epsilon = 1.0e-2);
+
+        assert_eq!(
+            powf::<S, D>(S::from_num(200), S::from_num(200)),
+            D::max_value()
+        );
+        assert_eq!(
+            powf::<S, D>(S::from_num(200), S::from_num(-200)),
+            D::from_num(0)
+        );
+    }
+
     #[test]
     fn powi_works() {
         type D = I32F32;
@@ -23,7 +23,7 @@ use crate::{
     FixedU8,
 };
 use core::{
-    fmt::{Display, Formatter, Result as FmtResult},
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
     iter::{Product, Sum},
     mem,
     ops::{
@@ -47,7 +47,7 @@ use core::{
 /// assert_eq!(I16F16::min_value(), (max + delta).0);
 /// ```
 #[repr(transparent)]
-#[derive(Clone, Copy, Default, Hash, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Clone, Copy, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Wrapping<F>(pub F);
 
 impl<F: Fixed> Wrapping<F> {
@@ -754,6 +754,13 @@ impl<F: Fixed> Display for Wrapping<F> {
     }
 }
 
+impl<F: Fixed> Debug for Wrapping<F> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        Debug::fmt(&self.0, f)
+    }
+}
+
 impl<F: Fixed> From<F> for Wrapping<F> {
     /// Wraps a fixed-point number.
     #[inline]
@@ -1130,3 +1137,42 @@ ops! { FixedU16(u16, LeEqU16) }
 ops! { FixedU32(u32, LeEqU32) }
 ops! { FixedU64(u64, LeEqU64) }
 ops! { FixedU128(u128, LeEqU128) }
+
+#[cfg(test)]
+mod tests {
+    use crate::{types::*, Wrapping};
+    use std::format;
+
+    #[test]
+    fn wrapping_ops() {
+        let max = Wrapping(I8F8::MAX);
+        let delta = Wrapping(I8F8::DELTA);
+        assert_eq!(max + delta, Wrapping(I8F8::MIN));
+        assert_eq!(Wrapping(I8F8::MIN) - delta, max);
+        assert_eq!(-Wrapping(I8F8::MIN), Wrapping(I8F8::MIN));
+        assert_eq!(
+            Wrapping(I8F8::from_num(100)) * Wrapping(I8F8::from_num(3)),
+            Wrapping(I8F8::from_num(44))
+        );
+
+        let mut acc = Wrapping(I16F16::from_num(32767));
+        acc += Wrapping(I16F16::from_num(2));
+        assert_eq!(acc, Wrapping(I16F16::from_num(-32767)));
+        acc -= Wrapping(I16F16::from_num(2));
+        assert_eq!(acc, Wrapping(I16F16::from_num(32767)));
+
+        assert_eq!(
+            Wrapping(U8F8::from_num(1)) - Wrapping(U8F8::from_num(2)),
+            Wrapping(U8F8::from_num(255))
+        );
+    }
+
+    #[test]
+    fn wrapping_fmt() {
+        let w = Wrapping(I16F16::from_num(-1.5));
+        assert_eq!(format!("{}", w), "-1.5");
+        assert_eq!(format!("{:?}", w), "I16F16(-1.5)");
+        assert_eq!(format!("{:>6}", w), "  -1.5");
+        assert_eq!(format!("{:?}", Wrapping(U8F8::from_num(3))), "U8F8(3)");
+    }
+}